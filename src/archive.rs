@@ -0,0 +1,300 @@
+//! Archive inspection for `--inspect-archives`.
+//!
+//! This module lets [`crate::scanner::scan_directory`] look inside `.tar`,
+//! `.tar.gz`/`.tgz`, and `.zip` files and report their members as ordinary
+//! [`FileEntry`] values, without extracting anything to disk. A tar header
+//! stream or a zip central directory is read just far enough to recover
+//! each member's name, size, and modification time.
+//!
+//! Both archive formats expose that information through different crate
+//! APIs, so [`FileLike`] abstracts "a thing with a name, size, and mtime"
+//! and [`member_to_file_entry`] does the one-time conversion into the
+//! `archive.tar!/inner/file.txt`-style [`FileEntry`] that the rest of the
+//! tool understands.
+
+use crate::error::{Result, RfstatError};
+use crate::types::FileEntry;
+use chrono::{DateTime, Utc};
+use std::collections::hash_map::DefaultHasher;
+use std::fs::File;
+use std::hash::{Hash, Hasher};
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+/// A thing with a name, size, and modification time.
+///
+/// Implemented for each archive format's member type so
+/// [`member_to_file_entry`] can build a [`FileEntry`] the same way
+/// regardless of whether the archive is a tarball or a zip.
+trait FileLike {
+    /// Name of the member, relative to the archive root (e.g. `src/main.rs`).
+    fn name(&self) -> &str;
+    /// Uncompressed size in bytes.
+    fn size(&self) -> u64;
+    /// Last modified timestamp (the current time when the archive's
+    /// timestamp is missing or invalid).
+    fn modified(&self) -> DateTime<Utc>;
+    /// Unix permission bits (zip members always report `0o644`).
+    fn permissions(&self) -> u32;
+}
+
+struct TarMember {
+    name: String,
+    size: u64,
+    modified: DateTime<Utc>,
+    mode: u32,
+}
+
+impl FileLike for TarMember {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn size(&self) -> u64 {
+        self.size
+    }
+
+    fn modified(&self) -> DateTime<Utc> {
+        self.modified
+    }
+
+    fn permissions(&self) -> u32 {
+        self.mode
+    }
+}
+
+struct ZipMember {
+    name: String,
+    size: u64,
+    modified: DateTime<Utc>,
+}
+
+impl FileLike for ZipMember {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn size(&self) -> u64 {
+        self.size
+    }
+
+    fn modified(&self) -> DateTime<Utc> {
+        self.modified
+    }
+
+    fn permissions(&self) -> u32 {
+        0o644 // zip's DOS-era permission bits aren't reliable; assume a regular file
+    }
+}
+
+/// Returns the uncompressed members of a `.tar`, `.tar.gz`/`.tgz`, or
+/// `.zip` file as [`FileEntry`] values, or an empty vector for any other
+/// file. Each member's `path` is rendered as `archive.tar!/inner/file.txt`
+/// so formatters and [`crate::stats::calculate_stats`] treat archived and
+/// real files uniformly.
+///
+/// # Examples
+///
+/// ```rust
+/// use rfstat::archive::read_archive_members;
+/// use std::path::Path;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let members = read_archive_members(Path::new("not-an-archive.txt"))?;
+/// assert!(members.is_empty());
+/// # Ok(())
+/// # }
+/// ```
+pub fn read_archive_members(path: &Path) -> Result<Vec<FileEntry>> {
+    let lower = path.to_string_lossy().to_lowercase();
+
+    if lower.ends_with(".tar.gz") || lower.ends_with(".tgz") {
+        read_tar_entries(path, true)
+    } else if lower.ends_with(".tar") {
+        read_tar_entries(path, false)
+    } else if lower.ends_with(".zip") {
+        read_zip_entries(path)
+    } else {
+        Ok(Vec::new())
+    }
+}
+
+/// Reads a tar header stream (optionally gzip-compressed) without
+/// extracting any member to disk.
+fn read_tar_entries(path: &Path, gzip: bool) -> Result<Vec<FileEntry>> {
+    let file = File::open(path)?;
+
+    if gzip {
+        collect_tar_entries(path, tar::Archive::new(flate2::read::GzDecoder::new(file)))
+    } else {
+        collect_tar_entries(path, tar::Archive::new(file))
+    }
+}
+
+/// Walks a tar entry stream, skipping directories. A member whose header
+/// can't be read fails the whole archive with that error.
+fn collect_tar_entries<R: Read>(
+    archive_path: &Path,
+    mut archive: tar::Archive<R>,
+) -> Result<Vec<FileEntry>> {
+    let mut entries = Vec::new();
+
+    for entry in archive.entries()? {
+        let entry = entry?;
+        let header = entry.header();
+
+        if header.entry_type().is_dir() {
+            continue;
+        }
+
+        let member = TarMember {
+            name: entry.path()?.to_string_lossy().to_string(),
+            size: header.size()?,
+            modified: DateTime::from_timestamp(header.mtime()? as i64, 0).unwrap_or_else(Utc::now),
+            mode: header.mode()?,
+        };
+
+        entries.push(member_to_file_entry(archive_path, &member));
+    }
+
+    Ok(entries)
+}
+
+/// Reads a zip file's central directory without decompressing any member.
+fn read_zip_entries(path: &Path) -> Result<Vec<FileEntry>> {
+    let file = File::open(path)?;
+    let mut archive =
+        zip::ZipArchive::new(file).map_err(|e| RfstatError::generic(e.to_string()))?;
+
+    let mut entries = Vec::new();
+    for i in 0..archive.len() {
+        let zip_file = archive
+            .by_index(i)
+            .map_err(|e| RfstatError::generic(e.to_string()))?;
+
+        if zip_file.is_dir() {
+            continue;
+        }
+
+        let member = ZipMember {
+            name: zip_file.name().to_string(),
+            size: zip_file.size(),
+            modified: zip_file
+                .last_modified()
+                .and_then(zip_datetime_to_utc)
+                .unwrap_or_else(Utc::now),
+        };
+
+        entries.push(member_to_file_entry(path, &member));
+    }
+
+    Ok(entries)
+}
+
+/// Converts a zip archive's DOS-era timestamp to a UTC [`DateTime`].
+fn zip_datetime_to_utc(dt: zip::DateTime) -> Option<DateTime<Utc>> {
+    use chrono::NaiveDate;
+
+    let naive = NaiveDate::from_ymd_opt(dt.year() as i32, dt.month() as u32, dt.day() as u32)?
+        .and_hms_opt(dt.hour() as u32, dt.minute() as u32, dt.second() as u32)?;
+    Some(DateTime::from_naive_utc_and_offset(naive, Utc))
+}
+
+/// Builds the `archive.tar!/inner/file.txt`-style [`FileEntry`] for a
+/// single archive member.
+fn member_to_file_entry(archive_path: &Path, member: &dyn FileLike) -> FileEntry {
+    let rendered_path = format!("{}!/{}", archive_path.display(), member.name());
+
+    // Archive members have no real inode; synthesize a stable one from the
+    // rendered path so hardlink-dedup accounting (see
+    // `stats::calculate_stats_with_options`) doesn't
+    // mistake unrelated members for the same data.
+    let mut hasher = DefaultHasher::new();
+    rendered_path.hash(&mut hasher);
+    let inode = (0, hasher.finish());
+
+    FileEntry {
+        path: PathBuf::from(rendered_path),
+        size: member.size(),
+        disk_usage: member.size(),
+        inode,
+        is_dir: false,
+        modified: member.modified(),
+        permissions: member.permissions(),
+        file_type: Path::new(member.name())
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .map(|s| s.to_lowercase()),
+        is_symlink: false,
+        symlink_target: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_read_archive_members_ignores_non_archives() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("plain.txt");
+        std::fs::write(&path, b"hello").unwrap();
+
+        let members = read_archive_members(&path).unwrap();
+        assert!(members.is_empty());
+    }
+
+    #[test]
+    fn test_read_tar_members() {
+        let temp_dir = TempDir::new().unwrap();
+        let tar_path = temp_dir.path().join("backup.tar");
+
+        {
+            let file = File::create(&tar_path).unwrap();
+            let mut builder = tar::Builder::new(file);
+            let data = b"inner contents";
+            let mut header = tar::Header::new_gnu();
+            header.set_size(data.len() as u64);
+            header.set_mode(0o644);
+            header.set_cksum();
+            builder
+                .append_data(&mut header, "nested/file.txt", &data[..])
+                .unwrap();
+            builder.finish().unwrap();
+        }
+
+        let members = read_archive_members(&tar_path).unwrap();
+        assert_eq!(members.len(), 1);
+        assert_eq!(members[0].size, 14);
+        assert!(members[0]
+            .path
+            .to_string_lossy()
+            .ends_with("backup.tar!/nested/file.txt"));
+    }
+
+    #[test]
+    fn test_read_zip_members() {
+        let temp_dir = TempDir::new().unwrap();
+        let zip_path = temp_dir.path().join("backup.zip");
+
+        {
+            let file = File::create(&zip_path).unwrap();
+            let mut writer = zip::ZipWriter::new(file);
+            writer
+                .start_file("nested/file.txt", zip::write::FileOptions::default())
+                .unwrap();
+            writer.write_all(b"inner contents").unwrap();
+            writer.finish().unwrap();
+        }
+
+        let members = read_archive_members(&zip_path).unwrap();
+        assert_eq!(members.len(), 1);
+        assert_eq!(members[0].size, 14);
+        assert!(members[0]
+            .path
+            .to_string_lossy()
+            .ends_with("backup.zip!/nested/file.txt"));
+    }
+}
@@ -3,7 +3,8 @@
 //! This module handles argument parsing and provides the main CLI structure
 //! for the rfstat application using the clap crate.
 
-use crate::types::{Config, OutputFormat, SortBy};
+use crate::scanner::SizeFilter;
+use crate::types::{Config, OutputFormat, SizeFormat, SortBy};
 use clap::{Parser, ValueEnum};
 use std::path::PathBuf;
 
@@ -63,13 +64,33 @@ pub struct Cli {
     #[arg(long, value_name = "EXTENSIONS")]
     pub extensions: Option<String>,
 
-    /// Minimum file size filter (e.g., "1MB", "500KB")
-    #[arg(long, value_name = "SIZE")]
-    pub min_size: Option<String>,
-
-    /// Maximum file size filter (e.g., "100MB", "1GB")
-    #[arg(long, value_name = "SIZE")]
-    pub max_size: Option<String>,
+    /// Filter by file size; prefix with `+` for greater-than, `-` for
+    /// less-than, or give an exact size to match it precisely (e.g.
+    /// "+1M", "-500k", "1024"). Repeat to combine into a range.
+    #[arg(long = "size", value_name = "SIZE")]
+    pub size: Vec<String>,
+
+    /// Skip paths matching this glob (repeatable, e.g. "*/node_modules/*").
+    /// Excluded directories are pruned and never descended into.
+    #[arg(long, value_name = "GLOB")]
+    pub exclude: Vec<String>,
+
+    /// Only include paths matching this glob (repeatable). Applied after
+    /// scanning, alongside filters like --extensions.
+    #[arg(long, value_name = "GLOB")]
+    pub include: Vec<String>,
+
+    /// Only include files whose base name matches this glob (repeatable,
+    /// e.g. "*.rs"). Unlike --include, this matches the file name alone,
+    /// not the full path.
+    #[arg(long, value_name = "GLOB")]
+    pub name: Vec<String>,
+
+    /// Only include files whose extension matches this regex (repeatable,
+    /// e.g. "jp?e?g"). All patterns are compiled into one RegexSet and
+    /// tested in a single pass.
+    #[arg(long = "extension-regex", value_name = "REGEX")]
+    pub extension_regex: Vec<String>,
 
     /// Enable verbose logging
     #[arg(short, long)]
@@ -86,6 +107,75 @@ pub struct Cli {
     /// Show modification times
     #[arg(long)]
     pub show_times: bool,
+
+    /// Find groups of byte-identical files (shorthand for --format duplicates)
+    #[arg(long)]
+    pub duplicates: bool,
+
+    /// Report on-disk allocated size instead of apparent (logical) size in
+    /// the table, summary, and size distribution
+    #[arg(long)]
+    pub disk_usage: bool,
+
+    /// Add a "Disk Size" column (and CSV/summary equivalents) showing
+    /// on-disk allocated size alongside the normal apparent size, instead
+    /// of replacing it the way `--disk-usage` does
+    #[arg(long)]
+    pub show_disk_size: bool,
+
+    /// Emit compact (non-pretty-printed) JSON with `--format json`
+    #[arg(long)]
+    pub json_compact: bool,
+
+    /// Display sizes in binary units (KiB, MiB, GiB) instead of decimal
+    /// (KB, MB, GB). Ignored if `--block-size` is also given.
+    #[arg(long)]
+    pub binary: bool,
+
+    /// Report sizes as a count of this block size, like `df --block-size`
+    /// (e.g. "1MiB" prints a 2.5 MiB file as "3"). Accepts the same
+    /// suffixes as `--size` and overrides `--binary`.
+    #[arg(long, value_name = "SIZE")]
+    pub block_size: Option<String>,
+
+    /// Count every hardlinked path toward totals instead of counting each
+    /// inode's size once (restores the naive, pre-dedup behavior)
+    #[arg(long)]
+    pub count_links: bool,
+
+    /// Number of worker threads for parallel directory scanning
+    /// (defaults to the available parallelism)
+    #[arg(long, value_name = "N")]
+    pub threads: Option<usize>,
+
+    /// Look inside .tar, .tar.gz/.tgz, and .zip files and report their
+    /// members alongside regular entries
+    #[arg(long)]
+    pub inspect_archives: bool,
+
+    /// Report only the N largest files (found with bounded memory, not by
+    /// sorting every entry). Composes with --extensions, --size, etc.
+    /// Takes precedence over --smallest if both are given.
+    #[arg(long, value_name = "N")]
+    pub top: Option<usize>,
+
+    /// Report only the N smallest non-empty files. Symmetric to --top;
+    /// also used as the ranking for `--format report`.
+    #[arg(long, value_name = "N")]
+    pub smallest: Option<usize>,
+
+    /// Descend into symlinked directories instead of treating them as leaf
+    /// entries. Cycles (a link pointing back at an ancestor) are detected
+    /// and pruned with a warning rather than looping forever.
+    #[arg(long)]
+    pub follow_symlinks: bool,
+
+    /// Where filename colors in the file table come from. `ls-colors`
+    /// honors the `LS_COLORS` environment variable the way `ls`/`exa` do;
+    /// `none` disables filename coloring (size-threshold coloring still
+    /// applies either way).
+    #[arg(long, value_enum, default_value_t = CliColorSource::Builtin)]
+    pub color_mode: CliColorSource,
 }
 
 /// CLI-compatible output format enum
@@ -95,10 +185,21 @@ pub enum CliOutputFormat {
     Table,
     /// JSON format for programmatic use
     Json,
+    /// Newline-delimited JSON for streaming into `jq`-style pipelines
+    JsonLines,
     /// CSV format for spreadsheet import
     Csv,
     /// Compact summary format
     Summary,
+    /// Duplicate-file report grouped by reclaimable space
+    Duplicates,
+    /// Indented tree view with recursive per-directory totals
+    Tree,
+    /// Proportional horizontal bar chart of file types and size distribution
+    Bars,
+    /// Detailed report: recursive directory-size stats, a log-scale size
+    /// histogram, and the ranked (largest or smallest) files
+    Report,
 }
 
 impl From<CliOutputFormat> for OutputFormat {
@@ -106,8 +207,13 @@ impl From<CliOutputFormat> for OutputFormat {
         match cli_format {
             CliOutputFormat::Table => OutputFormat::Table,
             CliOutputFormat::Json => OutputFormat::Json,
+            CliOutputFormat::JsonLines => OutputFormat::JsonLines,
             CliOutputFormat::Csv => OutputFormat::Csv,
             CliOutputFormat::Summary => OutputFormat::Summary,
+            CliOutputFormat::Duplicates => OutputFormat::Duplicates,
+            CliOutputFormat::Tree => OutputFormat::Tree,
+            CliOutputFormat::Bars => OutputFormat::Bars,
+            CliOutputFormat::Report => OutputFormat::Report,
         }
     }
 }
@@ -136,15 +242,52 @@ impl From<CliSortBy> for SortBy {
     }
 }
 
+/// CLI-compatible filename color source
+#[derive(ValueEnum, Clone, Debug)]
+pub enum CliColorSource {
+    /// The crate's own fixed directory/size-threshold colors
+    Builtin,
+    /// Parsed from the `LS_COLORS` environment variable
+    LsColors,
+    /// No filename coloring
+    None,
+}
+
+impl From<CliColorSource> for crate::colors::ColorSource {
+    fn from(cli_color_source: CliColorSource) -> Self {
+        match cli_color_source {
+            CliColorSource::Builtin => crate::colors::ColorSource::Builtin,
+            CliColorSource::LsColors => crate::colors::ColorSource::LsColors,
+            CliColorSource::None => crate::colors::ColorSource::None,
+        }
+    }
+}
+
 impl Cli {
     /// Converts CLI arguments to a Config struct
     pub fn to_config(&self) -> Config {
         Config {
-            format: self.format.clone().into(),
+            format: self.effective_format(),
             sort_by: self.sort.clone().into(),
             show_hidden: self.all,
             recursive: !self.no_recursive,
             max_depth: self.depth,
+            use_disk_usage: self.disk_usage,
+            count_links: self.count_links,
+            threads: self.threads,
+            exclude: self.exclude.clone(),
+            inspect_archives: self.inspect_archives,
+            follow_symlinks: self.follow_symlinks,
+        }
+    }
+
+    /// Returns the output format that should actually be used, honoring the
+    /// `--duplicates` shorthand over whatever `--format` was given.
+    pub fn effective_format(&self) -> OutputFormat {
+        if self.duplicates {
+            OutputFormat::Duplicates
+        } else {
+            self.format.clone().into()
         }
     }
 
@@ -191,19 +334,51 @@ impl Cli {
         })
     }
 
-    /// Gets the minimum size filter in bytes
-    pub fn get_min_size_bytes(&self) -> Result<Option<u64>, String> {
-        match &self.min_size {
-            Some(size_str) => Ok(Some(Self::parse_size(size_str)?)),
-            None => Ok(None),
+    /// Parses the repeated `--size` flags into `SizeFilter` comparisons.
+    ///
+    /// A leading `+` selects [`SizeFilter::GreaterThan`], a leading `-`
+    /// selects [`SizeFilter::LessThan`], and no prefix selects
+    /// [`SizeFilter::Equals`]. The remainder is parsed like
+    /// [`Cli::parse_size`], additionally accepting single-letter units such
+    /// as `1M` or `500k`.
+    pub fn parse_size_filters(&self) -> Result<Vec<SizeFilter>, String> {
+        self.size
+            .iter()
+            .map(|spec| {
+                let spec = spec.trim();
+                if let Some(rest) = spec.strip_prefix('+') {
+                    Ok(SizeFilter::GreaterThan(Self::parse_size_spec(rest)?))
+                } else if let Some(rest) = spec.strip_prefix('-') {
+                    Ok(SizeFilter::LessThan(Self::parse_size_spec(rest)?))
+                } else {
+                    Ok(SizeFilter::Equals(Self::parse_size_spec(spec)?))
+                }
+            })
+            .collect()
+    }
+
+    /// Parses one `--size`/`--block-size` value with [`Cli::parse_size`],
+    /// also accepting single-letter `fd`-style units (`K`, `M`, `G`, `T`,
+    /// any case) as their decimal `KB`/`MB`/`GB`/`TB` forms.
+    fn parse_size_spec(spec: &str) -> Result<u64, String> {
+        if spec.ends_with(|c: char| matches!(c.to_ascii_uppercase(), 'K' | 'M' | 'G' | 'T')) {
+            Self::parse_size(&format!("{spec}B"))
+        } else {
+            Self::parse_size(spec)
         }
     }
 
-    /// Gets the maximum size filter in bytes
-    pub fn get_max_size_bytes(&self) -> Result<Option<u64>, String> {
-        match &self.max_size {
-            Some(size_str) => Ok(Some(Self::parse_size(size_str)?)),
-            None => Ok(None),
+    /// Resolves `--block-size`/`--binary` into a [`SizeFormat`] for the
+    /// formatter. `--block-size` takes priority over `--binary` if both
+    /// are given; the block-size string is parsed the same permissive way
+    /// as `--size`.
+    pub fn parse_size_format(&self) -> Result<SizeFormat, String> {
+        if let Some(block_size) = &self.block_size {
+            Ok(SizeFormat::BlockSize(Self::parse_size_spec(block_size)?))
+        } else if self.binary {
+            Ok(SizeFormat::Binary)
+        } else {
+            Ok(SizeFormat::Decimal)
         }
     }
 }
@@ -230,10 +405,79 @@ mod tests {
             extensions: Some("txt,log,conf".to_string()),
             ..Default::default()
         };
-        
+
         let extensions = cli.parse_extensions().unwrap();
         assert_eq!(extensions, vec!["txt", "log", "conf"]);
     }
+
+    #[test]
+    fn test_parse_size_filters() {
+        let cli = Cli {
+            size: vec!["+1M".to_string(), "-10M".to_string()],
+            ..Default::default()
+        };
+
+        let filters = cli.parse_size_filters().unwrap();
+        assert_eq!(
+            filters,
+            vec![
+                SizeFilter::GreaterThan(1_000_000),
+                SizeFilter::LessThan(10_000_000),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_size_filters_exact_and_invalid() {
+        let cli = Cli {
+            size: vec!["1024".to_string()],
+            ..Default::default()
+        };
+        assert_eq!(
+            cli.parse_size_filters().unwrap(),
+            vec![SizeFilter::Equals(1024)]
+        );
+
+        let cli = Cli {
+            size: vec!["+invalid".to_string()],
+            ..Default::default()
+        };
+        assert!(cli.parse_size_filters().is_err());
+    }
+
+    #[test]
+    fn test_parse_size_format_defaults_to_decimal() {
+        let cli = Cli::default();
+        assert_eq!(cli.parse_size_format().unwrap(), SizeFormat::Decimal);
+    }
+
+    #[test]
+    fn test_parse_size_format_binary() {
+        let cli = Cli {
+            binary: true,
+            ..Default::default()
+        };
+        assert_eq!(cli.parse_size_format().unwrap(), SizeFormat::Binary);
+    }
+
+    #[test]
+    fn test_parse_size_format_block_size_overrides_binary() {
+        let cli = Cli {
+            binary: true,
+            block_size: Some("1MiB".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(cli.parse_size_format().unwrap(), SizeFormat::BlockSize(1_048_576));
+    }
+
+    #[test]
+    fn test_parse_size_format_invalid_block_size() {
+        let cli = Cli {
+            block_size: Some("nonsense".to_string()),
+            ..Default::default()
+        };
+        assert!(cli.parse_size_format().is_err());
+    }
 }
 
 // Implement Default for Cli to support testing
@@ -249,12 +493,28 @@ impl Default for Cli {
             limit: None,
             summary_only: false,
             extensions: None,
-            min_size: None,
-            max_size: None,
+            size: Vec::new(),
+            exclude: Vec::new(),
+            include: Vec::new(),
+            name: Vec::new(),
+            extension_regex: Vec::new(),
             verbose: false,
             quiet: false,
             show_permissions: false,
             show_times: false,
+            duplicates: false,
+            disk_usage: false,
+            show_disk_size: false,
+            json_compact: false,
+            binary: false,
+            block_size: None,
+            count_links: false,
+            threads: None,
+            inspect_archives: false,
+            top: None,
+            smallest: None,
+            follow_symlinks: false,
+            color_mode: CliColorSource::Builtin,
         }
     }
 }
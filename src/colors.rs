@@ -0,0 +1,157 @@
+//! `LS_COLORS`-aware ANSI styling for the file table.
+//!
+//! Mirrors how `ls`/`exa` color a filename: the `LS_COLORS` environment
+//! variable is parsed into glob/extension patterns and type indicators
+//! (`di`, `ln`, `ex`, `fi`, ...) mapped to SGR codes, then a filename is
+//! matched against those patterns to pick its style, falling back to the
+//! indicator for its type when no pattern matches.
+
+use globset::{Glob, GlobSet, GlobSetBuilder};
+use std::collections::HashMap;
+use std::env;
+
+/// Where [`crate::FormatterOptions`] should source filename colors from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ColorSource {
+    /// The crate's own fixed directory/size-threshold colors
+    #[default]
+    Builtin,
+    /// Parsed from the `LS_COLORS` environment variable, like `ls`/`exa`
+    LsColors,
+    /// No filename coloring at all
+    None,
+}
+
+/// A parsed `LS_COLORS` style table.
+///
+/// Built once via [`Self::from_env`] (or [`Self::parse`] for a specific
+/// string) and then queried per filename with [`Self::style`].
+pub struct LsColorsStyle {
+    globs: GlobSet,
+    glob_codes: Vec<String>,
+    indicators: HashMap<String, String>,
+}
+
+impl LsColorsStyle {
+    /// Parses the `LS_COLORS` environment variable, or an empty style
+    /// table (every filename falls back to its type indicator) if it's
+    /// unset.
+    pub fn from_env() -> Self {
+        Self::parse(&env::var("LS_COLORS").unwrap_or_default())
+    }
+
+    /// Parses an `LS_COLORS`-formatted string directly: colon-separated
+    /// `key=code` pairs, where a `*`-prefixed key is a glob/extension
+    /// pattern and anything else is a type indicator (`di`, `ln`, `ex`,
+    /// `fi`, ...).
+    pub fn parse(spec: &str) -> Self {
+        let mut builder = GlobSetBuilder::new();
+        let mut glob_codes = Vec::new();
+        let mut indicators = HashMap::new();
+
+        for entry in spec.split(':') {
+            let Some((key, code)) = entry.split_once('=') else {
+                continue;
+            };
+            if key.is_empty() || code.is_empty() {
+                continue;
+            }
+
+            if let Some(suffix) = key.strip_prefix('*') {
+                // LS_COLORS extension keys are literal suffixes (`*.tar.gz`),
+                // not full glob syntax, so turn them into a `*<suffix>` glob.
+                if let Ok(glob) = Glob::new(&format!("*{suffix}")) {
+                    builder.add(glob);
+                    glob_codes.push(code.to_string());
+                }
+            } else {
+                indicators.insert(key.to_string(), code.to_string());
+            }
+        }
+
+        let globs = builder
+            .build()
+            .unwrap_or_else(|_| GlobSetBuilder::new().build().expect("empty GlobSet always builds"));
+
+        Self {
+            globs,
+            glob_codes,
+            indicators,
+        }
+    }
+
+    /// Picks the SGR code for `name`: the last-matching glob/extension
+    /// pattern wins (matching `ls`'s "later entries override earlier
+    /// ones" rule), falling back to the type indicator for `di`/`ln`/`ex`,
+    /// then `fi` for a plain file.
+    fn code_for(&self, name: &str, is_dir: bool, is_symlink: bool, is_executable: bool) -> Option<&str> {
+        if let Some(&last_match) = self.globs.matches(name).last() {
+            return self.glob_codes.get(last_match).map(String::as_str);
+        }
+
+        let indicator = if is_dir {
+            "di"
+        } else if is_symlink {
+            "ln"
+        } else if is_executable {
+            "ex"
+        } else {
+            "fi"
+        };
+        self.indicators.get(indicator).map(String::as_str)
+    }
+
+    /// Wraps `name` in the matched style's ANSI escape sequence, or
+    /// returns it unchanged if nothing matched.
+    pub fn style(&self, name: &str, is_dir: bool, is_symlink: bool, is_executable: bool) -> String {
+        match self.code_for(name, is_dir, is_symlink, is_executable) {
+            Some(code) => format!("\x1b[{code}m{name}\x1b[0m"),
+            None => name.to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_empty_spec_has_no_styles() {
+        let styles = LsColorsStyle::parse("");
+        assert_eq!(styles.style("anything", false, false, false), "anything");
+    }
+
+    #[test]
+    fn test_parse_matches_extension_glob() {
+        let styles = LsColorsStyle::parse("*.tar=01;31:*.txt=00;32");
+        assert_eq!(styles.style("archive.tar", false, false, false), "\x1b[01;31marchive.tar\x1b[0m");
+        assert_eq!(styles.style("notes.txt", false, false, false), "\x1b[00;32mnotes.txt\x1b[0m");
+    }
+
+    #[test]
+    fn test_parse_falls_back_to_type_indicators() {
+        let styles = LsColorsStyle::parse("di=01;34:ln=01;36:ex=01;32:fi=00");
+        assert_eq!(styles.style("src", true, false, false), "\x1b[01;34msrc\x1b[0m");
+        assert_eq!(styles.style("link", false, true, false), "\x1b[01;36mlink\x1b[0m");
+        assert_eq!(styles.style("script.sh", false, false, true), "\x1b[01;32mscript.sh\x1b[0m");
+        assert_eq!(styles.style("readme", false, false, false), "\x1b[00mreadme\x1b[0m");
+    }
+
+    #[test]
+    fn test_glob_pattern_wins_over_type_indicator() {
+        let styles = LsColorsStyle::parse("fi=00:*.log=01;33");
+        assert_eq!(styles.style("app.log", false, false, false), "\x1b[01;33mapp.log\x1b[0m");
+    }
+
+    #[test]
+    fn test_later_matching_pattern_overrides_earlier() {
+        let styles = LsColorsStyle::parse("*.txt=00;32:*.txt=01;35");
+        assert_eq!(styles.style("notes.txt", false, false, false), "\x1b[01;35mnotes.txt\x1b[0m");
+    }
+
+    #[test]
+    fn test_parse_ignores_malformed_entries() {
+        let styles = LsColorsStyle::parse("garbage:*.txt=01;35:=nope:key=");
+        assert_eq!(styles.style("notes.txt", false, false, false), "\x1b[01;35mnotes.txt\x1b[0m");
+    }
+}
@@ -0,0 +1,203 @@
+//! Duplicate file detection.
+//!
+//! This module finds groups of byte-identical files within a collection of
+//! [`FileEntry`] records using the standard three-phase algorithm: files are
+//! first grouped by exact size (since files with a unique size can never be
+//! duplicates), each surviving size group is narrowed further by a cheap
+//! partial hash over the first few KB, and only files that still match are
+//! confirmed with a full-content hash. This keeps large trees fast by
+//! avoiding a full read of every file.
+
+use crate::types::{DuplicateGroup, FileEntry};
+use log::warn;
+use std::collections::HashMap;
+use std::fs::File;
+use std::hash::Hasher;
+use std::io::{self, Read};
+use std::path::Path;
+use twox_hash::XxHash64;
+
+/// Number of bytes read for the cheap partial-hash pass.
+const PARTIAL_HASH_BYTES: usize = 4096;
+
+/// Size of the chunks used while hashing full file contents.
+const FULL_HASH_CHUNK_BYTES: usize = 64 * 1024;
+
+/// Finds groups of byte-identical files among the given entries.
+///
+/// Directories and zero-length files are never considered duplicates. Files
+/// that fail to open or read are skipped with a warning rather than aborting
+/// the whole scan. Groups are returned sorted by reclaimable bytes
+/// (`size * (count - 1)`), largest first.
+///
+/// # Examples
+///
+/// ```rust
+/// use rfstat::duplicates::find_duplicates;
+/// use rfstat::FileEntry;
+///
+/// let entries: Vec<FileEntry> = Vec::new();
+/// assert!(find_duplicates(&entries).is_empty());
+/// ```
+pub fn find_duplicates(entries: &[FileEntry]) -> Vec<DuplicateGroup> {
+    let by_size = group_by_size(entries);
+
+    let mut groups = Vec::new();
+    for (size, candidates) in by_size {
+        if candidates.len() < 2 {
+            continue;
+        }
+
+        for partial_group in group_by_partial_hash(candidates) {
+            if partial_group.len() < 2 {
+                continue;
+            }
+
+            for full_group in group_by_full_hash(partial_group) {
+                if full_group.len() < 2 {
+                    continue;
+                }
+
+                let wasted_bytes = size * (full_group.len() as u64 - 1);
+                groups.push(DuplicateGroup {
+                    size,
+                    files: full_group.into_iter().map(|e| e.path.clone()).collect(),
+                    wasted_bytes,
+                });
+            }
+        }
+    }
+
+    groups.sort_by(|a, b| b.wasted_bytes.cmp(&a.wasted_bytes));
+    groups
+}
+
+/// Groups file entries by exact size, discarding directories and empty files.
+fn group_by_size(entries: &[FileEntry]) -> HashMap<u64, Vec<&FileEntry>> {
+    let mut by_size: HashMap<u64, Vec<&FileEntry>> = HashMap::new();
+    for entry in entries {
+        if entry.is_dir || entry.size == 0 {
+            continue;
+        }
+        by_size.entry(entry.size).or_default().push(entry);
+    }
+    by_size
+}
+
+/// Splits a same-size group further by a hash of its first
+/// `PARTIAL_HASH_BYTES` bytes.
+fn group_by_partial_hash(candidates: Vec<&FileEntry>) -> Vec<Vec<&FileEntry>> {
+    let mut by_hash: HashMap<u64, Vec<&FileEntry>> = HashMap::new();
+    for entry in candidates {
+        match hash_prefix(&entry.path, PARTIAL_HASH_BYTES) {
+            Ok(hash) => by_hash.entry(hash).or_default().push(entry),
+            Err(e) => warn!("Could not read {}: {}", entry.path.display(), e),
+        }
+    }
+    by_hash.into_values().collect()
+}
+
+/// Confirms a partial-hash group with a full-content hash.
+fn group_by_full_hash(candidates: Vec<&FileEntry>) -> Vec<Vec<&FileEntry>> {
+    let mut by_hash: HashMap<u64, Vec<&FileEntry>> = HashMap::new();
+    for entry in candidates {
+        match hash_file(&entry.path) {
+            Ok(hash) => by_hash.entry(hash).or_default().push(entry),
+            Err(e) => warn!("Could not read {}: {}", entry.path.display(), e),
+        }
+    }
+    by_hash.into_values().collect()
+}
+
+/// Hashes at most `limit` bytes from the start of a file.
+fn hash_prefix(path: &Path, limit: usize) -> io::Result<u64> {
+    let mut file = File::open(path)?;
+    let mut buf = vec![0u8; limit];
+    let mut read = 0;
+    while read < buf.len() {
+        let n = file.read(&mut buf[read..])?;
+        if n == 0 {
+            break;
+        }
+        read += n;
+    }
+    let mut hasher = XxHash64::with_seed(0);
+    hasher.write(&buf[..read]);
+    Ok(hasher.finish())
+}
+
+/// Hashes the full contents of a file in fixed-size chunks.
+fn hash_file(path: &Path) -> io::Result<u64> {
+    let mut file = File::open(path)?;
+    let mut hasher = XxHash64::with_seed(0);
+    let mut buf = [0u8; FULL_HASH_CHUNK_BYTES];
+    loop {
+        let n = file.read(&mut buf)?;
+        if n == 0 {
+            break;
+        }
+        hasher.write(&buf[..n]);
+    }
+    Ok(hasher.finish())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use std::fs;
+    use std::path::PathBuf;
+    use tempfile::TempDir;
+
+    fn entry_for(path: PathBuf, size: u64) -> FileEntry {
+        FileEntry {
+            path,
+            size,
+            disk_usage: size,
+            inode: (0, 0),
+            is_dir: false,
+            modified: Utc::now(),
+            permissions: 0o644,
+            file_type: None,
+            is_symlink: false,
+            symlink_target: None,
+        }
+    }
+
+    #[test]
+    fn test_find_duplicates_detects_identical_content() {
+        let temp_dir = TempDir::new().unwrap();
+
+        let a = temp_dir.path().join("a.txt");
+        let b = temp_dir.path().join("b.txt");
+        let c = temp_dir.path().join("c.txt");
+        fs::write(&a, b"hello world").unwrap();
+        fs::write(&b, b"hello world").unwrap();
+        fs::write(&c, b"different!!").unwrap();
+
+        let entries = vec![
+            entry_for(a.clone(), 11),
+            entry_for(b.clone(), 11),
+            entry_for(c, 11),
+        ];
+
+        let groups = find_duplicates(&entries);
+        assert_eq!(groups.len(), 1);
+        assert_eq!(groups[0].files.len(), 2);
+        assert_eq!(groups[0].wasted_bytes, 11);
+    }
+
+    #[test]
+    fn test_find_duplicates_ignores_unique_sizes() {
+        let temp_dir = TempDir::new().unwrap();
+
+        let a = temp_dir.path().join("a.txt");
+        let b = temp_dir.path().join("b.txt");
+        fs::write(&a, b"short").unwrap();
+        fs::write(&b, b"a much longer file").unwrap();
+
+        let entries = vec![entry_for(a, 5), entry_for(b, 18)];
+
+        assert!(find_duplicates(&entries).is_empty());
+    }
+}
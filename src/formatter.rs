@@ -4,10 +4,13 @@
 //! in various output formats including tables, JSON, CSV, and summary views.
 //! Each format is optimized for different use cases and workflows.
 
+use crate::colors::{ColorSource, LsColorsStyle};
 use crate::error::Result;
-use crate::types::{FileStats, OutputFormat};
+use crate::stats::{generate_summary_report_with_ranking, RankMode, SizeRanking};
+use crate::types::{format_size_as, FileStats, OutputFormat, SizeFormat, SortBy, TypeStats};
 use colored::*;
 use serde_json;
+use std::collections::BTreeMap;
 use std::io::Write;
 use tabled::{Table, Tabled};
 
@@ -42,8 +45,13 @@ pub fn format_output<W: Write>(
     match format {
         OutputFormat::Table => format_table(stats, writer, options),
         OutputFormat::Json => format_json(stats, writer, options),
+        OutputFormat::JsonLines => format_json_lines(stats, writer, options),
         OutputFormat::Csv => format_csv(stats, writer, options),
         OutputFormat::Summary => format_summary(stats, writer, options),
+        OutputFormat::Duplicates => format_duplicates(stats, writer, options),
+        OutputFormat::Tree => format_tree(stats, writer, options),
+        OutputFormat::Bars => format_bars(stats, writer, options),
+        OutputFormat::Report => format_report(stats, writer, options),
     }
 }
 
@@ -62,6 +70,33 @@ pub struct FormatterOptions {
     pub show_times: bool,
     /// Whether to show detailed file type breakdown
     pub show_file_types: bool,
+    /// Whether to report on-disk allocated size instead of apparent size
+    pub use_disk_usage: bool,
+    /// Whether to add an extra "Disk Size" column/field showing on-disk
+    /// allocated size alongside (not instead of) the apparent size. Unlike
+    /// `use_disk_usage`, this is additive and off by default to keep
+    /// default output compact.
+    pub show_disk_size: bool,
+    /// Whether the whole-document `OutputFormat::Json` output is pretty-
+    /// printed (the default) or compact. Does not affect `JsonLines`,
+    /// whose per-entry objects are always compact.
+    pub json_pretty: bool,
+    /// Unit base used when rendering byte counts as text (decimal, binary,
+    /// or a `df`-style block-size count). Applies to the file table,
+    /// summary header, tree, report, and the `size_human` fields in CSV
+    /// and JSON Lines; the file-type table and bars, the duplicate report,
+    /// the histogram range labels, and the compact `Summary` line always
+    /// use decimal units.
+    pub size_format: SizeFormat,
+    /// Ordering applied to entries within each directory of a tree view
+    pub sort_by: SortBy,
+    /// Where filename colors in the file table come from. Orthogonal to
+    /// the size-threshold coloring on the size column, which always
+    /// applies when `use_colors` is set regardless of this.
+    pub color_source: ColorSource,
+    /// Which files populate the ranked-files list in `OutputFormat::Report`
+    /// (set from `--top`/`--smallest`, defaulting to the 5 largest files).
+    pub ranking: SizeRanking,
 }
 
 impl Default for FormatterOptions {
@@ -73,6 +108,13 @@ impl Default for FormatterOptions {
             show_permissions: false,
             show_times: false,
             show_file_types: true,
+            use_disk_usage: false,
+            show_disk_size: false,
+            json_pretty: true,
+            size_format: SizeFormat::Decimal,
+            sort_by: SortBy::Name,
+            color_source: ColorSource::Builtin,
+            ranking: SizeRanking::default(),
         }
     }
 }
@@ -116,26 +158,53 @@ fn write_summary_header<W: Write>(
     
     writeln!(writer, "Total Files:      {}", format_number(stats.total_files))?;
     writeln!(writer, "Total Directories: {}", format_number(stats.total_dirs))?;
-    writeln!(writer, "Total Size:       {}", stats.total_size_human())?;
-    
+
+    if options.use_disk_usage {
+        writeln!(writer, "Total Disk Usage: {}", format_size_as(stats.total_disk_usage, options.size_format))?;
+    } else {
+        writeln!(writer, "Total Size:       {}", format_size_as(stats.total_size, options.size_format))?;
+        if stats.apparent_size != stats.total_size {
+            writeln!(writer, "Apparent Size:    {} (before hardlink dedup)", format_size_as(stats.apparent_size, options.size_format))?;
+        }
+        if options.show_disk_size {
+            writeln!(writer, "Total Disk Size:  {}", format_size_as(stats.total_disk_usage, options.size_format))?;
+            let slack = stats.total_size.saturating_sub(stats.total_disk_usage);
+            let overhead = stats.total_disk_usage.saturating_sub(stats.total_size);
+            if slack > 0 {
+                writeln!(writer, "  {} slack (apparent size exceeds disk usage)", format_size_as(slack, options.size_format))?;
+            } else if overhead > 0 {
+                writeln!(writer, "  {} overhead (disk usage exceeds apparent size)", format_size_as(overhead, options.size_format))?;
+            }
+        }
+    }
+
     if stats.total_files > 0 {
-        writeln!(writer, "Average File Size: {}", stats.avg_file_size_human())?;
-        writeln!(writer, "Largest File:     {}", humansize::format_size(stats.max_file_size, humansize::DECIMAL))?;
-        writeln!(writer, "Smallest File:    {}", humansize::format_size(
-            if stats.min_file_size == u64::MAX { 0 } else { stats.min_file_size },
-            humansize::DECIMAL
-        ))?;
+        if options.use_disk_usage {
+            writeln!(writer, "Average Disk Usage: {}", format_size_as(stats.avg_disk_usage, options.size_format))?;
+        } else {
+            writeln!(writer, "Average File Size: {}", format_size_as(stats.avg_file_size, options.size_format))?;
+            writeln!(writer, "Largest File:     {}", format_size_as(stats.max_file_size, options.size_format))?;
+            writeln!(writer, "Smallest File:    {}", format_size_as(
+                if stats.min_file_size == u64::MAX { 0 } else { stats.min_file_size },
+                options.size_format
+            ))?;
+        }
     }
-    
+
     // Size distribution
+    let distribution = if options.use_disk_usage {
+        &stats.disk_usage_distribution
+    } else {
+        &stats.size_distribution
+    };
     writeln!(writer)?;
     writeln!(writer, "Size Distribution:")?;
-    writeln!(writer, "  Tiny (< 1KB):     {}", format_number(stats.size_distribution.tiny))?;
-    writeln!(writer, "  Small (1KB-1MB):  {}", format_number(stats.size_distribution.small))?;
-    writeln!(writer, "  Medium (1MB-100MB): {}", format_number(stats.size_distribution.medium))?;
-    writeln!(writer, "  Large (100MB-1GB): {}", format_number(stats.size_distribution.large))?;
-    writeln!(writer, "  Huge (> 1GB):     {}", format_number(stats.size_distribution.huge))?;
-    
+    writeln!(writer, "  Tiny (< 1KB):     {}", format_number(distribution.tiny))?;
+    writeln!(writer, "  Small (1KB-1MB):  {}", format_number(distribution.small))?;
+    writeln!(writer, "  Medium (1MB-100MB): {}", format_number(distribution.medium))?;
+    writeln!(writer, "  Large (100MB-1GB): {}", format_number(distribution.large))?;
+    writeln!(writer, "  Huge (> 1GB):     {}", format_number(distribution.huge))?;
+
     Ok(())
 }
 
@@ -160,12 +229,33 @@ fn write_file_table<W: Write>(
         &stats.entries
     };
     
+    // Only built when actually needed, since it has to read the
+    // environment and parse the `LS_COLORS` spec.
+    let ls_colors = if options.use_colors && options.color_source == ColorSource::LsColors {
+        Some(LsColorsStyle::from_env())
+    } else {
+        None
+    };
+
     // Create table data
     let mut table_data = Vec::new();
     for entry in entries {
         let mut row = FileTableRow {
-            name: entry.name(),
-            size: entry.size_human(),
+            name: if let Some(target) = &entry.symlink_target {
+                format!("{} -> {}", entry.name(), target.display())
+            } else {
+                entry.name()
+            },
+            size: if options.use_disk_usage {
+                format_size_as(entry.disk_usage, options.size_format)
+            } else {
+                format_size_as(entry.size, options.size_format)
+            },
+            disk_size: if options.show_disk_size {
+                format_size_as(entry.disk_usage, options.size_format)
+            } else {
+                "".to_string()
+            },
             type_field: if entry.is_dir {
                 "DIR".to_string()
             } else {
@@ -185,12 +275,36 @@ fn write_file_table<W: Write>(
         
         // Apply colors if enabled
         if options.use_colors {
-            if entry.is_dir {
-                row.name = row.name.blue().to_string();
-                row.type_field = row.type_field.blue().to_string();
-            } else if entry.size > 100_000_000 { // > 100MB
+            match (&ls_colors, options.color_source) {
+                (Some(styles), ColorSource::LsColors) => {
+                    let is_executable = !entry.is_dir && (entry.permissions & 0o111) != 0;
+                    row.name = styles.style(&row.name, entry.is_dir, entry.is_symlink, is_executable);
+                    if entry.is_dir {
+                        row.type_field = row.type_field.blue().to_string();
+                    }
+                }
+                (_, ColorSource::None) => {}
+                _ => {
+                    // Builtin
+                    if entry.is_dir {
+                        row.name = row.name.blue().to_string();
+                        row.type_field = row.type_field.blue().to_string();
+                    }
+                }
+            }
+
+            // Size-threshold coloring is orthogonal to the filename color
+            // source above, so it applies no matter which one is active.
+            let display_size = if options.use_disk_usage {
+                entry.disk_usage
+            } else {
+                entry.size
+            };
+            if display_size > 100_000_000 {
+                // > 100MB
                 row.size = row.size.red().to_string();
-            } else if entry.size > 1_000_000 { // > 1MB
+            } else if display_size > 1_000_000 {
+                // > 1MB
                 row.size = row.size.yellow().to_string();
             }
         }
@@ -242,17 +356,292 @@ fn write_file_types_table<W: Write>(
     Ok(())
 }
 
+/// A single node in the directory tree built for [`format_tree`].
+///
+/// Leaf nodes are files; nodes with children are directories. `total_size`
+/// is the recursive sum of every descendant file, accumulated in a
+/// post-order pass after the tree is built.
+#[derive(Debug, Default)]
+struct TreeNode {
+    is_dir: bool,
+    /// Own size; only meaningful for files
+    size: u64,
+    /// Recursive size of this node and all descendants
+    total_size: u64,
+    children: BTreeMap<String, TreeNode>,
+}
+
+impl TreeNode {
+    fn insert(&mut self, components: &[String], is_dir: bool, size: u64) {
+        let Some((first, rest)) = components.split_first() else {
+            return;
+        };
+
+        let child = self.children.entry(first.clone()).or_default();
+        if rest.is_empty() {
+            child.is_dir = is_dir;
+            child.size = size;
+        } else {
+            child.is_dir = true;
+            child.insert(rest, is_dir, size);
+        }
+    }
+
+    /// Accumulates descendant sizes into `total_size`, post-order.
+    fn finalize(&mut self) -> u64 {
+        if self.children.is_empty() {
+            self.total_size = self.size;
+        } else {
+            self.total_size = self.children.values_mut().map(TreeNode::finalize).sum();
+        }
+        self.total_size
+    }
+}
+
+/// Finds the longest shared path prefix across all entries, so the tree can
+/// be rendered relative to the scanned root rather than from the filesystem
+/// root.
+fn common_path_prefix(stats: &FileStats) -> Vec<String> {
+    let mut entries = stats.entries.iter();
+    let Some(first) = entries.next() else {
+        return Vec::new();
+    };
+
+    let mut prefix: Vec<String> = first
+        .path
+        .components()
+        .map(|c| c.as_os_str().to_string_lossy().to_string())
+        .collect();
+
+    for entry in entries {
+        let components: Vec<String> = entry
+            .path
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy().to_string())
+            .collect();
+
+        let shared = prefix
+            .iter()
+            .zip(components.iter())
+            .take_while(|(a, b)| a == b)
+            .count();
+        prefix.truncate(shared);
+    }
+
+    // The last shared component belongs to a leaf entry itself in the
+    // single-entry case; drop it so the prefix only covers directories.
+    if prefix.len() == first.path.components().count() {
+        prefix.pop();
+    }
+
+    prefix
+}
+
+/// Builds the directory tree for `stats.entries`, relative to their common
+/// ancestor, with recursive sizes already accumulated.
+///
+/// Uses each entry's disk usage instead of its apparent size when
+/// `options.use_disk_usage` is set, matching the table/CSV/summary
+/// outputs.
+fn build_tree(stats: &FileStats, options: &FormatterOptions) -> TreeNode {
+    let prefix = common_path_prefix(stats);
+    let mut root = TreeNode {
+        is_dir: true,
+        ..Default::default()
+    };
+
+    for entry in &stats.entries {
+        let components: Vec<String> = entry
+            .path
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy().to_string())
+            .collect();
+
+        if components.len() <= prefix.len() {
+            continue;
+        }
+
+        let size = if options.use_disk_usage {
+            entry.disk_usage
+        } else {
+            entry.size
+        };
+        root.insert(&components[prefix.len()..], entry.is_dir, size);
+    }
+
+    root.finalize();
+    root
+}
+
+/// Formats output as an indented tree with recursive per-directory totals.
+fn format_tree<W: Write>(
+    stats: &FileStats,
+    writer: &mut W,
+    options: &FormatterOptions,
+) -> Result<()> {
+    let title = if options.use_colors {
+        "🌳 Directory Tree".bold().green()
+    } else {
+        "Directory Tree".normal()
+    };
+    writeln!(writer, "{}", title)?;
+    writeln!(writer, "{}", "-".repeat(30))?;
+
+    let tree = build_tree(stats, options);
+    write_tree_children(writer, &tree, "", options)?;
+
+    writeln!(
+        writer,
+        "\nTotal: {}",
+        format_size_as(tree.total_size, options.size_format)
+    )?;
+
+    Ok(())
+}
+
+/// Recursively writes a node's children with box-drawing prefixes.
+///
+/// Children beyond `options.limit` are elided with a trailing
+/// "… (N more)" line rather than silently dropped, so a capped tree still
+/// tells the reader how much was cut. Node names and sizes are styled the
+/// same way the flat file table styles them: `options.color_source`
+/// governs directory name coloring and the size-threshold red/yellow
+/// coloring applies to every node's size regardless of source.
+fn write_tree_children<W: Write>(
+    writer: &mut W,
+    node: &TreeNode,
+    prefix: &str,
+    options: &FormatterOptions,
+) -> Result<()> {
+    let mut children: Vec<(&String, &TreeNode)> = node.children.iter().collect();
+    sort_tree_children(&mut children, options.sort_by);
+
+    let total = children.len();
+    let shown = options.limit.unwrap_or(total).min(total);
+    let elided = total - shown;
+
+    let ls_colors = if options.use_colors && options.color_source == ColorSource::LsColors {
+        Some(LsColorsStyle::from_env())
+    } else {
+        None
+    };
+
+    for (i, (name, child)) in children.iter().take(shown).enumerate() {
+        let is_last = i == shown - 1 && elided == 0;
+        let connector = if is_last { "└── " } else { "├── " };
+        let mut size_human = format_size_as(child.total_size, options.size_format);
+
+        let mut display_name = name.to_string();
+        if options.use_colors {
+            match (&ls_colors, options.color_source) {
+                (Some(styles), ColorSource::LsColors) => {
+                    display_name = styles.style(&display_name, child.is_dir, false, false);
+                }
+                (_, ColorSource::None) => {}
+                _ => {
+                    if child.is_dir {
+                        display_name = display_name.blue().to_string();
+                    }
+                }
+            }
+
+            if child.total_size > 100_000_000 {
+                size_human = size_human.red().to_string();
+            } else if child.total_size > 1_000_000 {
+                size_human = size_human.yellow().to_string();
+            }
+        }
+
+        writeln!(writer, "{}{}{} ({})", prefix, connector, display_name, size_human)?;
+
+        if child.is_dir && !child.children.is_empty() {
+            let child_prefix = format!("{}{}", prefix, if is_last { "    " } else { "│   " });
+            write_tree_children(writer, child, &child_prefix, options)?;
+        }
+    }
+
+    if elided > 0 {
+        writeln!(writer, "{}└── … ({} more)", prefix, elided)?;
+    }
+
+    Ok(())
+}
+
+/// Orders a directory's children: `Size` by recursive size, largest first,
+/// and `Name` by name. Tree nodes carry no modification time or file type,
+/// so `Modified` and `Type` order every node by name as well.
+fn sort_tree_children(children: &mut [(&String, &TreeNode)], sort_by: SortBy) {
+    match sort_by {
+        SortBy::Size => children.sort_by(|a, b| b.1.total_size.cmp(&a.1.total_size)),
+        SortBy::Name | SortBy::Modified | SortBy::Type => {
+            children.sort_by(|a, b| a.0.cmp(b.0));
+        }
+    }
+}
+
 /// Formats output as JSON.
 fn format_json<W: Write>(
     stats: &FileStats,
     writer: &mut W,
-    _options: &FormatterOptions,
+    options: &FormatterOptions,
 ) -> Result<()> {
-    let json = serde_json::to_string_pretty(stats)?;
+    let json = if options.json_pretty {
+        serde_json::to_string_pretty(stats)?
+    } else {
+        serde_json::to_string(stats)?
+    };
     writeln!(writer, "{}", json)?;
     Ok(())
 }
 
+/// Formats output as newline-delimited JSON (NDJSON): one compact object
+/// per file entry, written as it goes rather than buffering the whole
+/// `FileStats` document, followed by a summary object tagged
+/// with `"type":"summary"` so a `jq` pipeline can tell entries and the
+/// trailing summary apart.
+fn format_json_lines<W: Write>(
+    stats: &FileStats,
+    writer: &mut W,
+    options: &FormatterOptions,
+) -> Result<()> {
+    if !options.summary_only {
+        let entries = if let Some(limit) = options.limit {
+            &stats.entries[..stats.entries.len().min(limit)]
+        } else {
+            &stats.entries
+        };
+
+        for entry in entries {
+            let mut record = serde_json::json!({
+                "path": entry.path,
+                "size": entry.size,
+                "size_human": format_size_as(entry.size, options.size_format),
+                "is_dir": entry.is_dir,
+                "file_type": entry.file_type,
+                "permissions": entry.permissions,
+                "modified": entry.modified,
+            });
+            if options.show_disk_size {
+                record["size_on_disk"] = serde_json::json!(entry.disk_usage);
+            }
+            writeln!(writer, "{}", serde_json::to_string(&record)?)?;
+        }
+    }
+
+    writeln!(
+        writer,
+        "{}",
+        serde_json::to_string(&serde_json::json!({
+            "type": "summary",
+            "total_files": stats.total_files,
+            "total_dirs": stats.total_dirs,
+            "total_size": stats.total_size,
+        }))?
+    )?;
+
+    Ok(())
+}
+
 /// Formats output as CSV.
 fn format_csv<W: Write>(
     stats: &FileStats,
@@ -263,6 +652,9 @@ fn format_csv<W: Write>(
     
     // Write header
     let mut headers = vec!["path", "size_bytes", "size_human", "is_directory", "file_type"];
+    if options.show_disk_size {
+        headers.push("size_on_disk_bytes");
+    }
     if options.show_permissions {
         headers.push("permissions");
     }
@@ -282,11 +674,14 @@ fn format_csv<W: Write>(
         let mut record = vec![
             entry.path.to_string_lossy().to_string(),
             entry.size.to_string(),
-            entry.size_human(),
+            format_size_as(entry.size, options.size_format),
             entry.is_dir.to_string(),
             entry.file_type.as_deref().unwrap_or("").to_string(),
         ];
-        
+
+        if options.show_disk_size {
+            record.push(entry.disk_usage.to_string());
+        }
         if options.show_permissions {
             record.push(format!("{:o}", entry.permissions));
         }
@@ -316,6 +711,357 @@ fn format_summary<W: Write>(
     Ok(())
 }
 
+/// Formats output as a duplicate-file report.
+fn format_duplicates<W: Write>(
+    stats: &FileStats,
+    writer: &mut W,
+    options: &FormatterOptions,
+) -> Result<()> {
+    let title = if options.use_colors {
+        "🗃️  Duplicate Files".bold().magenta()
+    } else {
+        "Duplicate Files".normal()
+    };
+
+    writeln!(writer, "{}", title)?;
+    writeln!(writer, "{}", "=".repeat(50))?;
+
+    if stats.duplicate_groups.is_empty() {
+        writeln!(writer, "No duplicate files found.")?;
+        return Ok(());
+    }
+
+    let groups = if let Some(limit) = options.limit {
+        &stats.duplicate_groups[..stats.duplicate_groups.len().min(limit)]
+    } else {
+        &stats.duplicate_groups[..]
+    };
+
+    for (i, group) in groups.iter().enumerate() {
+        let header = format!(
+            "[{}] {} wasted across {} copies of {} each",
+            i + 1,
+            group.wasted_bytes_human(),
+            group.files.len(),
+            group.size_human()
+        );
+        let header = if options.use_colors {
+            header.yellow().to_string()
+        } else {
+            header
+        };
+        writeln!(writer, "{}", header)?;
+
+        for file in &group.files {
+            writeln!(writer, "  {}", file.display())?;
+        }
+    }
+
+    writeln!(writer)?;
+    writeln!(
+        writer,
+        "Total reclaimable space: {}",
+        humansize::format_size(stats.wasted_bytes, humansize::DECIMAL)
+    )?;
+
+    Ok(())
+}
+
+/// Formats output as proportional horizontal bar charts: first the
+/// file-type breakdown by bytes, then the size-distribution buckets by
+/// file count, both scaled to the terminal width.
+fn format_bars<W: Write>(
+    stats: &FileStats,
+    writer: &mut W,
+    options: &FormatterOptions,
+) -> Result<()> {
+    write_summary_header(writer, stats, options)?;
+    writeln!(writer)?;
+    write_size_bars(writer, stats, options)?;
+    Ok(())
+}
+
+/// Formats output as a detailed report: recursive directory-size stats,
+/// a log-scale size histogram, and the files selected by `options.ranking`.
+fn format_report<W: Write>(
+    stats: &FileStats,
+    writer: &mut W,
+    options: &FormatterOptions,
+) -> Result<()> {
+    let report = generate_summary_report_with_ranking(stats, options.ranking);
+
+    write_summary_header(writer, stats, options)?;
+    writeln!(writer)?;
+
+    let title = if options.use_colors {
+        "📁 Directory Stats".bold().green()
+    } else {
+        "Directory Stats".normal()
+    };
+    writeln!(writer, "{}", title)?;
+    writeln!(writer, "Total Directories: {}", format_number(report.total_directories))?;
+    writeln!(writer, "Max Depth:        {}", report.max_directory_depth)?;
+    if let Some(largest) = &report.largest_directory {
+        writeln!(
+            writer,
+            "Largest Directory: {} ({})",
+            largest,
+            format_size_as(report.largest_directory_size, options.size_format)
+        )?;
+    }
+    writeln!(writer)?;
+
+    let title = if options.use_colors {
+        "📊 Size Histogram".bold().green()
+    } else {
+        "Size Histogram".normal()
+    };
+    writeln!(writer, "{}", title)?;
+    let histogram_rows: Vec<BarRow> = report
+        .size_histogram
+        .iter()
+        .map(|bucket| BarRow {
+            label: bucket.range_human(),
+            value: bucket.count,
+            display: format_number(bucket.count),
+        })
+        .collect();
+    render_bars(writer, &histogram_rows, stats.total_files, options)?;
+    writeln!(writer)?;
+
+    let ranking_label = match report.ranking.mode {
+        RankMode::Largest => "Largest Files",
+        RankMode::Smallest => "Smallest Files",
+    };
+    let title = if options.use_colors {
+        format!("📄 {}", ranking_label).bold().green()
+    } else {
+        ranking_label.normal()
+    };
+    writeln!(writer, "{}", title)?;
+    for entry in &report.ranked_files {
+        writeln!(
+            writer,
+            "  {:<10} {}",
+            format_size_as(entry.size, options.size_format),
+            entry.path.display()
+        )?;
+    }
+
+    Ok(())
+}
+
+/// One row of a [`write_size_bars`] chart: a label, the raw value its bar
+/// width is proportional to, and the already-formatted text shown after
+/// the bar (a human size or a plain count, depending on the series).
+struct BarRow {
+    label: String,
+    value: u64,
+    display: String,
+}
+
+/// Draws both bar-chart sections: the file-type breakdown (bytes per
+/// extension) and the size-distribution buckets (files per size range).
+fn write_size_bars<W: Write>(
+    writer: &mut W,
+    stats: &FileStats,
+    options: &FormatterOptions,
+) -> Result<()> {
+    write_file_type_bars(writer, stats, options)?;
+    if !stats.file_types.is_empty() {
+        writeln!(writer)?;
+    }
+    write_size_distribution_bars(writer, stats, options)?;
+    Ok(())
+}
+
+fn write_file_type_bars<W: Write>(
+    writer: &mut W,
+    stats: &FileStats,
+    options: &FormatterOptions,
+) -> Result<()> {
+    let title = if options.use_colors {
+        "📊 File Type Breakdown".bold().green()
+    } else {
+        "File Type Breakdown".normal()
+    };
+    writeln!(writer, "{}", title)?;
+
+    let mut types: Vec<(&String, &TypeStats)> = stats.file_types.iter().collect();
+    types.sort_by(|a, b| b.1.total_size.cmp(&a.1.total_size));
+
+    let rows: Vec<BarRow> = types
+        .into_iter()
+        .map(|(name, type_stats)| BarRow {
+            label: name.clone(),
+            value: type_stats.total_size,
+            display: humansize::format_size(type_stats.total_size, humansize::DECIMAL),
+        })
+        .collect();
+
+    render_bars(writer, &rows, stats.total_size, options)
+}
+
+fn write_size_distribution_bars<W: Write>(
+    writer: &mut W,
+    stats: &FileStats,
+    options: &FormatterOptions,
+) -> Result<()> {
+    let title = if options.use_colors {
+        "📊 Size Distribution".bold().green()
+    } else {
+        "Size Distribution".normal()
+    };
+    writeln!(writer, "{}", title)?;
+
+    let distribution = if options.use_disk_usage {
+        &stats.disk_usage_distribution
+    } else {
+        &stats.size_distribution
+    };
+    let rows = vec![
+        BarRow {
+            label: "Tiny (< 1KB)".to_string(),
+            value: distribution.tiny,
+            display: format_number(distribution.tiny),
+        },
+        BarRow {
+            label: "Small (1KB-1MB)".to_string(),
+            value: distribution.small,
+            display: format_number(distribution.small),
+        },
+        BarRow {
+            label: "Medium (1MB-100MB)".to_string(),
+            value: distribution.medium,
+            display: format_number(distribution.medium),
+        },
+        BarRow {
+            label: "Large (100MB-1GB)".to_string(),
+            value: distribution.large,
+            display: format_number(distribution.large),
+        },
+        BarRow {
+            label: "Huge (> 1GB)".to_string(),
+            value: distribution.huge,
+            display: format_number(distribution.huge),
+        },
+    ];
+
+    render_bars(writer, &rows, stats.total_files, options)
+}
+
+/// Renders `rows` as bars proportional to the largest value among them
+/// (capped at `options.limit` rows), annotated with each row's
+/// already-formatted value and its percentage share of `total`.
+fn render_bars<W: Write>(
+    writer: &mut W,
+    rows: &[BarRow],
+    total: u64,
+    options: &FormatterOptions,
+) -> Result<()> {
+    if rows.is_empty() {
+        return Ok(());
+    }
+
+    let rows = if let Some(limit) = options.limit {
+        &rows[..rows.len().min(limit)]
+    } else {
+        rows
+    };
+
+    let label_width = rows.iter().map(|r| r.label.chars().count()).max().unwrap_or(0);
+    let display_width = rows.iter().map(|r| r.display.chars().count()).max().unwrap_or(0);
+    let bar_width = bar_render_width(label_width, display_width);
+    let max_value = rows.iter().map(|r| r.value).max().unwrap_or(0);
+
+    for row in rows {
+        let bar = render_bar(row.value, max_value, bar_width);
+        let bar = if options.use_colors {
+            bar.cyan().to_string()
+        } else {
+            bar
+        };
+        let pct = if total > 0 {
+            (row.value as f64 / total as f64) * 100.0
+        } else {
+            0.0
+        };
+        writeln!(
+            writer,
+            "{:<label_width$}  [{}]  {} ({:.1}%)",
+            row.label,
+            bar,
+            row.display,
+            pct,
+            label_width = label_width
+        )?;
+    }
+
+    Ok(())
+}
+
+/// The eighth-block characters for each fractional remainder `0..=7`,
+/// where index `k` covers the `k/8` partial fill (index `0` is blank).
+const EIGHTHS: [char; 8] = [
+    ' ', '\u{258F}', '\u{258E}', '\u{258D}', '\u{258C}', '\u{258B}', '\u{258A}', '\u{2589}',
+];
+
+/// Renders one bar of `width` columns, `value / max_value` full, using
+/// full blocks (`█`) plus one partial eighth-block for the fractional
+/// remainder, padded with spaces to `width`.
+fn render_bar(value: u64, max_value: u64, width: usize) -> String {
+    if max_value == 0 || width == 0 {
+        return " ".repeat(width);
+    }
+
+    let filled = (value as f64 / max_value as f64) * width as f64;
+    let mut full_blocks = filled.floor() as usize;
+    let mut eighths_index = ((filled - filled.floor()) * 8.0).round() as usize;
+    if eighths_index >= 8 {
+        full_blocks += 1;
+        eighths_index = 0;
+    }
+    full_blocks = full_blocks.min(width);
+
+    let mut bar = "\u{2588}".repeat(full_blocks);
+    if full_blocks < width {
+        if eighths_index > 0 {
+            bar.push(EIGHTHS[eighths_index]);
+            bar.push_str(&" ".repeat(width - full_blocks - 1));
+        } else {
+            bar.push_str(&" ".repeat(width - full_blocks));
+        }
+    }
+    bar
+}
+
+/// Determines the available bar width: the terminal's column count (see
+/// [`terminal_width`]), minus room for the label, brackets,
+/// spacing, and the trailing `display (pct%)` annotation.
+fn bar_render_width(label_width: usize, display_width: usize) -> usize {
+    let term_width = terminal_width();
+    // "label  [bar]  display (100.0%)"
+    let reserved = label_width + display_width + 2 /* brackets */ + 4 /* two double-spaces */ + 9 /* " (100.0%)" */;
+    term_width.saturating_sub(reserved).max(10)
+}
+
+/// Determines the terminal width by querying the controlling terminal
+/// directly, since `$COLUMNS` is a shell variable that isn't exported to
+/// child processes by default and so is rarely set in practice. Falls
+/// back to `$COLUMNS` when there's no real terminal (output is piped or
+/// redirected), then to 80 columns when neither is available.
+fn terminal_width() -> usize {
+    terminal_size::terminal_size()
+        .map(|(terminal_size::Width(w), _)| w as usize)
+        .or_else(|| {
+            std::env::var("COLUMNS")
+                .ok()
+                .and_then(|s| s.parse::<usize>().ok())
+        })
+        .filter(|&w| w > 0)
+        .unwrap_or(80)
+}
+
 /// Helper function to format numbers with thousand separators.
 fn format_number(n: u64) -> String {
     let s = n.to_string();
@@ -339,6 +1085,8 @@ struct FileTableRow {
     name: String,
     #[tabled(rename = "Size")]
     size: String,
+    #[tabled(rename = "Disk Size")]
+    disk_size: String,
     #[tabled(rename = "Type")]
     type_field: String,
     #[tabled(rename = "Permissions")]
@@ -363,7 +1111,66 @@ struct FileTypeRow {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::types::FileStats;
+    use crate::types::{FileEntry, FileStats};
+    use chrono::Utc;
+    use std::path::PathBuf;
+
+    fn entry(name: &str, ext: &str) -> FileEntry {
+        FileEntry {
+            path: PathBuf::from(name),
+            size: 10,
+            disk_usage: 10,
+            inode: (0, 1),
+            is_dir: false,
+            modified: Utc::now(),
+            permissions: 0o644,
+            file_type: Some(ext.to_string()),
+            is_symlink: false,
+            symlink_target: None,
+        }
+    }
+
+    #[test]
+    fn test_write_file_table_ls_colors_styles_matched_extension() {
+        std::env::set_var("LS_COLORS", "*.log=01;33");
+
+        let mut stats = FileStats::new();
+        stats.entries = vec![entry("app.log", "log")];
+
+        let options = FormatterOptions {
+            color_source: ColorSource::LsColors,
+            ..FormatterOptions::default()
+        };
+
+        let mut output = Vec::new();
+        write_file_table(&mut output, &stats, &options).unwrap();
+        let rendered = String::from_utf8(output).unwrap();
+
+        assert!(rendered.contains("\x1b[01;33m"));
+
+        std::env::remove_var("LS_COLORS");
+    }
+
+    #[test]
+    fn test_write_file_table_color_source_none_skips_filename_styling() {
+        let mut stats = FileStats::new();
+        stats.entries = vec![entry("app.log", "log")];
+
+        let options = FormatterOptions {
+            color_source: ColorSource::None,
+            ..FormatterOptions::default()
+        };
+
+        let mut output = Vec::new();
+        write_file_table(&mut output, &stats, &options).unwrap();
+        let rendered = String::from_utf8(output).unwrap();
+
+        assert!(rendered.contains("app.log"));
+        // A styled name would have the "m" ending its SGR escape immediately
+        // before it;
+        // with ColorSource::None there's nothing wrapping the filename.
+        assert!(!rendered.contains("mapp.log"));
+    }
 
     #[test]
     fn test_format_number() {
@@ -372,6 +1179,131 @@ mod tests {
         assert_eq!(format_number(123), "123");
     }
 
+    #[test]
+    fn test_render_bar_full_width_for_max_value() {
+        let bar = render_bar(100, 100, 10);
+        assert_eq!(bar, "\u{2588}".repeat(10));
+    }
+
+    #[test]
+    fn test_render_bar_empty_for_zero_value() {
+        let bar = render_bar(0, 100, 10);
+        assert_eq!(bar, " ".repeat(10));
+    }
+
+    #[test]
+    fn test_render_bar_partial_block_for_fractional_fill() {
+        // 5/10 of width 10 is an exact 5 full blocks with no remainder.
+        let bar = render_bar(5, 10, 10);
+        assert_eq!(bar, "\u{2588}".repeat(5) + &" ".repeat(5));
+
+        // 1/3 of width 9 is 3.0 full blocks exactly; nudge to a fraction.
+        let bar = render_bar(1, 3, 10);
+        let chars: Vec<char> = bar.chars().collect();
+        assert_eq!(chars.len(), 10);
+        assert_eq!(chars[0], '\u{2588}'); // 10/3 ~= 3.33 filled -> at least 1 full block
+        // The 0.33 remainder rounds to 3/8, drawn as a partial block after
+        // the 3 full ones.
+        assert_eq!(chars[3], '\u{258D}');
+    }
+
+    #[test]
+    fn test_write_size_bars_file_type_breakdown() {
+        let mut stats = FileStats::new();
+        stats.total_size = 300;
+        stats.total_files = 2;
+        stats.file_types.insert(
+            "txt".to_string(),
+            TypeStats {
+                count: 1,
+                total_size: 200,
+                avg_size: 200,
+            },
+        );
+        stats.file_types.insert(
+            "log".to_string(),
+            TypeStats {
+                count: 1,
+                total_size: 100,
+                avg_size: 100,
+            },
+        );
+
+        let options = FormatterOptions {
+            use_colors: false,
+            ..FormatterOptions::default()
+        };
+
+        let mut output = Vec::new();
+        write_size_bars(&mut output, &stats, &options).unwrap();
+        let rendered = String::from_utf8(output).unwrap();
+
+        assert!(rendered.contains("File Type Breakdown"));
+        assert!(rendered.contains("Size Distribution"));
+        assert!(rendered.contains("txt"));
+        assert!(rendered.contains("66.7%"));
+    }
+
+    #[test]
+    fn test_write_size_bars_respects_limit() {
+        let mut stats = FileStats::new();
+        stats.total_size = 30;
+        for (ext, size) in [("a", 10u64), ("b", 10), ("c", 10)] {
+            stats.file_types.insert(
+                ext.to_string(),
+                TypeStats {
+                    count: 1,
+                    total_size: size,
+                    avg_size: size,
+                },
+            );
+        }
+
+        let options = FormatterOptions {
+            use_colors: false,
+            limit: Some(1),
+            ..FormatterOptions::default()
+        };
+
+        let mut output = Vec::new();
+        write_size_bars(&mut output, &stats, &options).unwrap();
+        let rendered = String::from_utf8(output).unwrap();
+        let type_breakdown_lines = rendered
+            .lines()
+            .skip_while(|l| !l.contains("File Type Breakdown"))
+            .skip(1)
+            .take_while(|l| !l.is_empty())
+            .count();
+        assert_eq!(type_breakdown_lines, 1);
+    }
+
+    #[test]
+    fn test_format_report_includes_directory_and_ranked_files() {
+        let mut stats = FileStats::new();
+        stats.total_files = 2;
+        stats.entries = vec![
+            FileEntry { path: PathBuf::from("/a/big.txt"), size: 200, ..entry("big.txt", "txt") },
+            FileEntry { path: PathBuf::from("/a/small.txt"), size: 10, ..entry("small.txt", "txt") },
+        ];
+        stats.total_size = 210;
+
+        let options = FormatterOptions {
+            use_colors: false,
+            ranking: SizeRanking::largest(1),
+            ..FormatterOptions::default()
+        };
+
+        let mut output = Vec::new();
+        format_report(&stats, &mut output, &options).unwrap();
+        let rendered = String::from_utf8(output).unwrap();
+
+        assert!(rendered.contains("Directory Stats"));
+        assert!(rendered.contains("Size Histogram"));
+        assert!(rendered.contains("Largest Files"));
+        assert!(rendered.contains("big.txt"));
+        assert!(!rendered.contains("small.txt"));
+    }
+
     #[test]
     fn test_format_json() {
         let stats = FileStats::new();
@@ -398,4 +1330,299 @@ mod tests {
         assert!(summary_str.contains("Dirs:"));
         assert!(summary_str.contains("Size:"));
     }
+
+    #[test]
+    fn test_format_csv_includes_disk_size_column_when_enabled() {
+        let mut stats = FileStats::new();
+        let mut e = entry("sparse.bin", "bin");
+        e.size = 1_000_000;
+        e.disk_usage = 4096;
+        stats.entries = vec![e];
+
+        let options = FormatterOptions {
+            show_disk_size: true,
+            ..FormatterOptions::default()
+        };
+
+        let mut output = Vec::new();
+        format_csv(&stats, &mut output, &options).unwrap();
+        let csv_str = String::from_utf8(output).unwrap();
+
+        let mut lines = csv_str.lines();
+        assert!(lines.next().unwrap().contains("size_on_disk_bytes"));
+        assert!(lines.next().unwrap().contains("4096"));
+    }
+
+    #[test]
+    fn test_format_csv_omits_disk_size_column_by_default() {
+        let mut stats = FileStats::new();
+        stats.entries = vec![entry("app.log", "log")];
+
+        let options = FormatterOptions::default();
+
+        let mut output = Vec::new();
+        format_csv(&stats, &mut output, &options).unwrap();
+        let csv_str = String::from_utf8(output).unwrap();
+
+        assert!(!csv_str.lines().next().unwrap().contains("size_on_disk_bytes"));
+    }
+
+    #[test]
+    fn test_write_summary_header_shows_disk_size_slack() {
+        let mut stats = FileStats::new();
+        stats.total_files = 1;
+        stats.total_size = 1_000_000;
+        stats.apparent_size = 1_000_000;
+        stats.total_disk_usage = 4096;
+
+        let options = FormatterOptions {
+            use_colors: false,
+            show_disk_size: true,
+            ..FormatterOptions::default()
+        };
+
+        let mut output = Vec::new();
+        write_summary_header(&mut output, &stats, &options).unwrap();
+        let rendered = String::from_utf8(output).unwrap();
+
+        assert!(rendered.contains("Total Disk Size:"));
+        assert!(rendered.contains("slack"));
+    }
+
+    #[test]
+    fn test_write_file_table_shows_disk_size_column_when_enabled() {
+        let mut stats = FileStats::new();
+        let mut e = entry("sparse.bin", "bin");
+        e.disk_usage = 4096;
+        stats.entries = vec![e];
+
+        let options = FormatterOptions {
+            use_colors: false,
+            show_disk_size: true,
+            ..FormatterOptions::default()
+        };
+
+        let mut output = Vec::new();
+        write_file_table(&mut output, &stats, &options).unwrap();
+        let rendered = String::from_utf8(output).unwrap();
+
+        assert!(rendered.contains("Disk Size"));
+    }
+
+    #[test]
+    fn test_format_json_compact_omits_indentation() {
+        let stats = FileStats::new();
+        let options = FormatterOptions {
+            json_pretty: false,
+            ..FormatterOptions::default()
+        };
+
+        let mut output = Vec::new();
+        format_json(&stats, &mut output, &options).unwrap();
+        let json_str = String::from_utf8(output).unwrap();
+
+        assert!(json_str.contains("total_files"));
+        assert!(!json_str.contains('\n'));
+    }
+
+    #[test]
+    fn test_format_json_lines_writes_one_object_per_entry_and_a_summary() {
+        let mut stats = FileStats::new();
+        stats.total_files = 2;
+        stats.entries = vec![entry("a.log", "log"), entry("b.log", "log")];
+
+        let options = FormatterOptions::default();
+
+        let mut output = Vec::new();
+        format_json_lines(&stats, &mut output, &options).unwrap();
+        let lines: Vec<_> = String::from_utf8(output).unwrap().lines().map(String::from).collect();
+
+        assert_eq!(lines.len(), 3);
+        assert!(lines[0].contains("\"path\":\"a.log\""));
+        assert!(lines[1].contains("\"path\":\"b.log\""));
+        assert!(lines[2].contains("\"type\":\"summary\""));
+    }
+
+    #[test]
+    fn test_format_json_lines_respects_limit() {
+        let mut stats = FileStats::new();
+        stats.entries = vec![entry("a.log", "log"), entry("b.log", "log")];
+
+        let options = FormatterOptions {
+            limit: Some(1),
+            ..FormatterOptions::default()
+        };
+
+        let mut output = Vec::new();
+        format_json_lines(&stats, &mut output, &options).unwrap();
+        let lines: Vec<_> = String::from_utf8(output).unwrap().lines().map(String::from).collect();
+
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].contains("\"path\":\"a.log\""));
+    }
+
+    #[test]
+    fn test_format_json_lines_includes_disk_size_when_enabled() {
+        let mut stats = FileStats::new();
+        let mut e = entry("sparse.bin", "bin");
+        e.disk_usage = 4096;
+        stats.entries = vec![e];
+
+        let options = FormatterOptions {
+            show_disk_size: true,
+            ..FormatterOptions::default()
+        };
+
+        let mut output = Vec::new();
+        format_json_lines(&stats, &mut output, &options).unwrap();
+        let json_str = String::from_utf8(output).unwrap();
+
+        assert!(json_str.contains("\"size_on_disk\":4096"));
+    }
+
+    #[test]
+    fn test_write_file_table_block_size_format_shows_block_count() {
+        let mut stats = FileStats::new();
+        let mut e = entry("big.bin", "bin");
+        e.size = 2_621_440;
+        stats.entries = vec![e];
+
+        let options = FormatterOptions {
+            use_colors: false,
+            size_format: SizeFormat::BlockSize(1_048_576),
+            ..FormatterOptions::default()
+        };
+
+        let mut output = Vec::new();
+        write_file_table(&mut output, &stats, &options).unwrap();
+        let rendered = String::from_utf8(output).unwrap();
+
+        assert!(rendered.contains("big.bin"));
+        assert!(!rendered.contains("MB"));
+        assert!(!rendered.contains("MiB"));
+    }
+
+    #[test]
+    fn test_write_summary_header_honors_size_format() {
+        let mut stats = FileStats::new();
+        stats.total_size = 1_048_576;
+        stats.apparent_size = 1_048_576;
+
+        let options = FormatterOptions {
+            use_colors: false,
+            size_format: SizeFormat::Binary,
+            ..FormatterOptions::default()
+        };
+
+        let mut output = Vec::new();
+        write_summary_header(&mut output, &stats, &options).unwrap();
+        let rendered = String::from_utf8(output).unwrap();
+
+        assert!(rendered.contains(&humansize::format_size(1_048_576u64, humansize::BINARY)));
+    }
+
+    #[test]
+    fn test_format_json_lines_includes_size_human() {
+        let mut stats = FileStats::new();
+        stats.entries = vec![entry("app.log", "log")];
+
+        let options = FormatterOptions {
+            size_format: SizeFormat::Binary,
+            ..FormatterOptions::default()
+        };
+
+        let mut output = Vec::new();
+        format_json_lines(&stats, &mut output, &options).unwrap();
+        let json_str = String::from_utf8(output).unwrap();
+
+        assert!(json_str.contains(&format!("\"size_human\":\"{}\"", humansize::format_size(10u64, humansize::BINARY))));
+    }
+
+    #[test]
+    fn test_write_tree_children_elides_beyond_limit() {
+        let mut a = entry("dir/a.txt", "txt");
+        a.size = 10;
+        let mut b = entry("dir/b.txt", "txt");
+        b.size = 10;
+        let mut c = entry("dir/c.txt", "txt");
+        c.size = 10;
+
+        let mut stats = FileStats::new();
+        stats.entries = vec![a, b, c];
+
+        let options = FormatterOptions {
+            use_colors: false,
+            limit: Some(1),
+            ..FormatterOptions::default()
+        };
+
+        let mut output = Vec::new();
+        format_tree(&stats, &mut output, &options).unwrap();
+        let rendered = String::from_utf8(output).unwrap();
+
+        assert!(rendered.contains("… (2 more)"));
+        assert_eq!(rendered.matches("a.txt").count() + rendered.matches("b.txt").count() + rendered.matches("c.txt").count(), 1);
+    }
+
+    #[test]
+    fn test_write_tree_children_colors_large_file_size() {
+        let mut big = entry("dir/big.bin", "bin");
+        big.size = 200_000_000;
+
+        let mut stats = FileStats::new();
+        stats.entries = vec![big];
+
+        let options = FormatterOptions::default();
+
+        let mut output = Vec::new();
+        format_tree(&stats, &mut output, &options).unwrap();
+        let rendered = String::from_utf8(output).unwrap();
+
+        assert!(rendered.contains("big.bin"));
+        assert!(rendered.contains("\x1b[31m"));
+    }
+
+    #[test]
+    fn test_format_tree_uses_size_format() {
+        let mut e = entry("file.bin", "bin");
+        e.size = 1_048_576;
+
+        let mut stats = FileStats::new();
+        stats.entries = vec![e];
+
+        let options = FormatterOptions {
+            use_colors: false,
+            size_format: SizeFormat::Binary,
+            ..FormatterOptions::default()
+        };
+
+        let mut output = Vec::new();
+        format_tree(&stats, &mut output, &options).unwrap();
+        let rendered = String::from_utf8(output).unwrap();
+
+        assert!(rendered.contains(&humansize::format_size(1_048_576u64, humansize::BINARY)));
+    }
+
+    #[test]
+    fn test_format_tree_uses_disk_usage_when_enabled() {
+        let mut e = entry("file.bin", "bin");
+        e.size = 1_048_576;
+        e.disk_usage = 4_096;
+
+        let mut stats = FileStats::new();
+        stats.entries = vec![e];
+
+        let options = FormatterOptions {
+            use_colors: false,
+            use_disk_usage: true,
+            ..FormatterOptions::default()
+        };
+
+        let mut output = Vec::new();
+        format_tree(&stats, &mut output, &options).unwrap();
+        let rendered = String::from_utf8(output).unwrap();
+
+        assert!(rendered.contains(&humansize::format_size(4_096u64, humansize::DECIMAL)));
+        assert!(!rendered.contains(&humansize::format_size(1_048_576u64, humansize::DECIMAL)));
+    }
 }
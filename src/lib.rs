@@ -31,7 +31,10 @@
 //! - [`types`]: Core data structures and type definitions
 //! - [`scanner`]: File system traversal and metadata collection
 //! - [`stats`]: Statistical analysis and calculations
+//! - [`duplicates`]: Byte-identical file detection
+//! - [`archive`]: Tar/zip archive inspection
 //! - [`formatter`]: Output formatting for different formats
+//! - [`colors`]: `LS_COLORS`-aware ANSI styling for the file table
 //! - [`cli`]: Command-line interface and argument parsing
 //!
 //! ## Examples
@@ -54,13 +57,19 @@
 pub mod types;
 pub mod scanner;
 pub mod stats;
+pub mod duplicates;
+pub mod archive;
 pub mod formatter;
+pub mod colors;
 pub mod cli;
 pub mod error;
 
 pub use types::*;
 pub use scanner::*;
 pub use stats::*;
+pub use duplicates::*;
+pub use archive::*;
+pub use colors::*;
 pub use error::*;
 pub use formatter::*;
 pub use cli::*;
@@ -75,4 +84,10 @@ pub const DEFAULT_CONFIG: Config = Config {
     show_hidden: false,
     recursive: true,
     max_depth: None,
+    use_disk_usage: false,
+    count_links: false,
+    threads: None,
+    exclude: Vec::new(),
+    inspect_archives: false,
+    follow_symlinks: false,
 };
@@ -7,8 +7,10 @@
 use clap::Parser;
 use log::{debug, error, info, warn};
 use rfstat::{
-    calculate_stats, filter_entries, format_output, scan_directory, sort_entries,
-    scanner::FileFilters, Cli, Config, FormatterOptions, Result, RfstatError,
+    calculate_stats_parallel, calculate_stats_with_options, filter_entries, find_duplicates,
+    format_output, get_smallest_files, scan_directory_parallel, sort_entries,
+    scanner::FileFilters, top_n_largest_files, Cli, Config, FormatterOptions, OutputFormat,
+    Result, RfstatError, SizeRanking, StatsAccumulator,
 };
 use std::io::{self, IsTerminal};
 use std::process;
@@ -45,28 +47,78 @@ fn run(cli: Cli) -> Result<()> {
     // Convert CLI args to config
     let config = cli.to_config();
     debug!("Configuration: {:?}", config);
+
+    let size_format = cli.parse_size_format()
+        .map_err(|e| RfstatError::config(format!("Invalid block size: {}", e)))?;
     
     // Scan the directory
     info!("Scanning directory: {}", cli.path.display());
-    let mut entries = scan_directory(&cli.path, &config)?;
+    let mut entries = scan_directory_parallel(&cli.path, &config)?;
     info!("Found {} entries", entries.len());
     
     // Apply additional filters from CLI
     let filters = create_file_filters(&cli)?;
     if has_active_filters(&filters) {
         let original_count = entries.len();
-        entries = filter_entries(&entries, &filters);
+        entries = filter_entries(&entries, &filters)?;
         debug!("Filtered from {} to {} entries", original_count, entries.len());
     }
     
-    // Sort entries
-    sort_entries(&mut entries, config.sort_by);
-    debug!("Sorted entries by {:?}", config.sort_by);
-    
-    // Calculate statistics
-    let stats = calculate_stats(&entries);
-    debug!("Calculated statistics for {} files, {} directories", 
+    // A `--top`/`--smallest N` request replaces the entry list with just
+    // the N largest/smallest files, already sorted by size, so it takes
+    // the place of --sort here. --top wins if both are given.
+    if let Some(n) = cli.top {
+        entries = top_n_largest_files(&entries, n);
+        debug!("Narrowed to the {} largest files", entries.len());
+    } else if let Some(n) = cli.smallest {
+        entries = get_smallest_files(&entries, n, 1).into_iter().cloned().collect();
+        debug!("Narrowed to the {} smallest files", entries.len());
+    } else {
+        sort_entries(&mut entries, config.sort_by);
+        debug!("Sorted entries by {:?}", config.sort_by);
+    }
+
+    // The ranking `--format report` uses to pick its highlighted files,
+    // matching whichever of --top/--smallest (if either) the user passed.
+    let ranking = if let Some(n) = cli.top {
+        SizeRanking::largest(n)
+    } else if let Some(n) = cli.smallest {
+        SizeRanking::smallest(n, 1)
+    } else {
+        SizeRanking::default()
+    };
+
+    // Calculate statistics. Summary and Bars never read `stats.entries`,
+    // so they can use the streaming `StatsAccumulator` instead of
+    // `calculate_stats_with_options`, which clones every entry into
+    // `stats.entries` up front; every other format needs the full entry
+    // list (for the file table, tree, ranked files, etc.) so it can't.
+    // `calculate_stats_parallel` doesn't synchronize hardlink state across
+    // chunk boundaries, so it only matches the serial path exactly when
+    // `count_links` is already true (no dedup to get wrong); that's also
+    // the only case where it's worth the rayon overhead here, since scans
+    // this large are rare with --count-links off.
+    let mut stats = if matches!(config.format, OutputFormat::Summary | OutputFormat::Bars) {
+        let mut accumulator = StatsAccumulator::with_count_links(config.count_links);
+        for entry in &entries {
+            accumulator.push(entry);
+        }
+        accumulator.finalize()
+    } else if config.count_links {
+        calculate_stats_parallel(&entries)
+    } else {
+        calculate_stats_with_options(&entries, config.count_links)
+    };
+    debug!("Calculated statistics for {} files, {} directories",
            stats.total_files, stats.total_dirs);
+
+    // Duplicate detection reads file contents, so only run it when requested
+    if config.format == OutputFormat::Duplicates {
+        let duplicate_groups = find_duplicates(&entries);
+        stats.wasted_bytes = duplicate_groups.iter().map(|g| g.wasted_bytes).sum();
+        stats.duplicate_groups = duplicate_groups;
+        debug!("Found {} duplicate groups", stats.duplicate_groups.len());
+    }
     
     // Create formatter options
     let formatter_options = FormatterOptions {
@@ -76,6 +128,13 @@ fn run(cli: Cli) -> Result<()> {
         show_permissions: cli.show_permissions,
         show_times: cli.show_times,
         show_file_types: !cli.summary_only,
+        use_disk_usage: config.use_disk_usage,
+        show_disk_size: cli.show_disk_size,
+        json_pretty: !cli.json_compact,
+        size_format,
+        sort_by: config.sort_by,
+        color_source: cli.color_mode.clone().into(),
+        ranking,
     };
     
     // Format and output results
@@ -114,15 +173,15 @@ fn init_logging(cli: &Cli) -> Result<()> {
 /// Creates file filters from CLI arguments.
 fn create_file_filters(cli: &Cli) -> Result<FileFilters> {
     let extensions = cli.parse_extensions();
-    let min_size = cli.get_min_size_bytes()
-        .map_err(|e| RfstatError::config(format!("Invalid min-size: {}", e)))?;
-    let max_size = cli.get_max_size_bytes()
-        .map_err(|e| RfstatError::config(format!("Invalid max-size: {}", e)))?;
-    
+    let size_filters = cli.parse_size_filters()
+        .map_err(|e| RfstatError::config(format!("Invalid size filter: {}", e)))?;
+
     Ok(FileFilters {
         extensions,
-        min_size,
-        max_size,
+        size_filters,
+        include: cli.include.clone(),
+        name_patterns: cli.name.clone(),
+        extension_regex: cli.extension_regex.clone(),
         files_only: false,
         dirs_only: false,
     })
@@ -130,10 +189,12 @@ fn create_file_filters(cli: &Cli) -> Result<FileFilters> {
 
 /// Checks if any filters are active.
 fn has_active_filters(filters: &FileFilters) -> bool {
-    filters.extensions.is_some() 
-        || filters.min_size.is_some() 
-        || filters.max_size.is_some()
-        || filters.files_only 
+    filters.extensions.is_some()
+        || !filters.size_filters.is_empty()
+        || !filters.include.is_empty()
+        || !filters.name_patterns.is_empty()
+        || !filters.extension_regex.is_empty()
+        || filters.files_only
         || filters.dirs_only
 }
 
@@ -148,10 +209,15 @@ fn should_use_colors(cli: &Cli) -> bool {
     }
     
     match cli.format {
-        rfstat::cli::CliOutputFormat::Json | rfstat::cli::CliOutputFormat::Csv => false,
-        rfstat::cli::CliOutputFormat::Table | rfstat::cli::CliOutputFormat::Summary => {
-            io::stdout().is_terminal()
-        }
+        rfstat::cli::CliOutputFormat::Json
+        | rfstat::cli::CliOutputFormat::JsonLines
+        | rfstat::cli::CliOutputFormat::Csv => false,
+        rfstat::cli::CliOutputFormat::Table
+        | rfstat::cli::CliOutputFormat::Summary
+        | rfstat::cli::CliOutputFormat::Duplicates
+        | rfstat::cli::CliOutputFormat::Tree
+        | rfstat::cli::CliOutputFormat::Bars
+        | rfstat::cli::CliOutputFormat::Report => io::stdout().is_terminal(),
     }
 }
 
@@ -172,30 +238,32 @@ mod tests {
     fn test_create_file_filters_empty() {
         let cli = Cli {
             extensions: None,
-            min_size: None,
-            max_size: None,
+            size: Vec::new(),
             ..Default::default()
         };
-        
+
         let filters = create_file_filters(&cli).unwrap();
         assert!(filters.extensions.is_none());
-        assert!(filters.min_size.is_none());
-        assert!(filters.max_size.is_none());
+        assert!(filters.size_filters.is_empty());
     }
 
     #[test]
     fn test_create_file_filters_with_values() {
         let cli = Cli {
             extensions: Some("txt,log".to_string()),
-            min_size: Some("1KB".to_string()),
-            max_size: Some("1MB".to_string()),
+            size: vec!["+1KB".to_string(), "-1MB".to_string()],
             ..Default::default()
         };
-        
+
         let filters = create_file_filters(&cli).unwrap();
         assert_eq!(filters.extensions, Some(vec!["txt".to_string(), "log".to_string()]));
-        assert_eq!(filters.min_size, Some(1000));
-        assert_eq!(filters.max_size, Some(1_000_000));
+        assert_eq!(
+            filters.size_filters,
+            vec![
+                rfstat::scanner::SizeFilter::GreaterThan(1000),
+                rfstat::scanner::SizeFilter::LessThan(1_000_000),
+            ]
+        );
     }
 
     #[test]
@@ -5,7 +5,9 @@
 //! file type breakdowns, and summary metrics.
 
 use crate::types::{FileEntry, FileStats, SizeDistribution, TypeStats};
-use std::collections::HashMap;
+use rayon::prelude::*;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::path::PathBuf;
 
 /// Calculates comprehensive statistics from a collection of file entries.
 ///
@@ -28,10 +30,14 @@ use std::collections::HashMap;
 ///     FileEntry {
 ///         path: PathBuf::from("test.txt"),
 ///         size: 1024,
+///         disk_usage: 4096,
+///         inode: (0, 1),
 ///         is_dir: false,
 ///         modified: Utc::now(),
 ///         permissions: 0o644,
 ///         file_type: Some("txt".to_string()),
+///         is_symlink: false,
+///         symlink_target: None,
 ///     }
 /// ];
 ///
@@ -40,8 +46,20 @@ use std::collections::HashMap;
 /// assert_eq!(stats.total_size, 1024);
 /// ```
 pub fn calculate_stats(entries: &[FileEntry]) -> FileStats {
+    calculate_stats_with_options(entries, false)
+}
+
+/// Calculates comprehensive statistics, with control over hardlink handling.
+///
+/// When `count_links` is `false` (the default used by [`calculate_stats`]),
+/// a file's size and disk usage are only added the first time its
+/// `(device_id, inode)` identity is seen, so hardlinked copies of the same
+/// data aren't double-counted. Passing `true` restores the naive behavior
+/// of summing every path regardless of shared inodes.
+pub fn calculate_stats_with_options(entries: &[FileEntry], count_links: bool) -> FileStats {
     let mut stats = FileStats::new();
     let mut file_sizes = Vec::new();
+    let mut seen_inodes: HashSet<(u64, u64)> = HashSet::new();
 
     // First pass: collect basic statistics
     for entry in entries {
@@ -49,26 +67,37 @@ pub fn calculate_stats(entries: &[FileEntry]) -> FileStats {
             stats.total_dirs += 1;
         } else {
             stats.total_files += 1;
-            stats.total_size += entry.size;
-            file_sizes.push(entry.size);
+            stats.apparent_size += entry.size;
 
-            // Update size distribution
-            stats.size_distribution.add_size(entry.size);
+            // Only the first path seen for a given inode contributes to the
+            // size totals; later hardlinks to the same data are skipped.
+            let already_seen_inode = !seen_inodes.insert(entry.inode);
+            let is_duplicate_link = !count_links && already_seen_inode;
 
-            // Update file type statistics
             let file_type = entry.file_type.as_deref().unwrap_or("no_extension");
             let type_stats = stats
                 .file_types
                 .entry(file_type.to_string())
                 .or_default();
             type_stats.count += 1;
-            type_stats.total_size += entry.size;
+
+            if !is_duplicate_link {
+                stats.total_size += entry.size;
+                stats.total_disk_usage += entry.disk_usage;
+                file_sizes.push(entry.size);
+
+                stats.size_distribution.add_size(entry.size);
+                stats.disk_usage_distribution.add_size(entry.disk_usage);
+
+                type_stats.total_size += entry.size;
+            }
         }
     }
 
     // Calculate derived statistics
     if stats.total_files > 0 {
         stats.avg_file_size = stats.total_size / stats.total_files;
+        stats.avg_disk_usage = stats.total_disk_usage / stats.total_files;
 
         if !file_sizes.is_empty() {
             stats.max_file_size = *file_sizes.iter().max().unwrap();
@@ -89,6 +118,37 @@ pub fn calculate_stats(entries: &[FileEntry]) -> FileStats {
     stats
 }
 
+/// Entry-count threshold above which [`calculate_stats_parallel`] chunks
+/// and parallelizes; below it, the chunking/merging overhead isn't worth
+/// it and it just calls the serial path directly.
+pub const PARALLEL_STATS_THRESHOLD: usize = 10_000;
+
+/// Computes the same aggregate statistics as [`calculate_stats_with_options`]
+/// (counts, totals, per-type breakdown, size distribution, min/max), but
+/// partitions `entries` into chunks processed in parallel with rayon, then
+/// folds the partial `FileStats` back together with [`FileStats::merge`].
+///
+/// Hardlinks are **never** deduped: every chunk, and the serial fallback
+/// below the threshold, is computed with `count_links = true`, so the
+/// result matches `calculate_stats_with_options(entries, true)`. This keeps
+/// the merge a cheap, embarrassingly parallel fold instead of needing a
+/// synchronized, tree-wide inode set. Prefer `calculate_stats_with_options`
+/// when accurate hardlink accounting matters more than raw throughput.
+///
+/// Falls back to the serial path below [`PARALLEL_STATS_THRESHOLD`]
+/// entries, where spinning up chunks costs more than it saves.
+pub fn calculate_stats_parallel(entries: &[FileEntry]) -> FileStats {
+    if entries.len() < PARALLEL_STATS_THRESHOLD {
+        return calculate_stats_with_options(entries, true);
+    }
+
+    let chunk_size = (entries.len() / rayon::current_num_threads()).max(1);
+    entries
+        .par_chunks(chunk_size)
+        .map(|chunk| calculate_stats_with_options(chunk, true))
+        .reduce(FileStats::new, FileStats::merge)
+}
+
 /// Calculates the top N largest files from the entries.
 ///
 /// # Arguments
@@ -105,6 +165,58 @@ pub fn get_largest_files(entries: &[FileEntry], n: usize) -> Vec<&FileEntry> {
     files.into_iter().take(n).collect()
 }
 
+/// Finds the `n` largest files without sorting the full entry list.
+///
+/// Entries stream into a `BTreeMap<u64, Vec<FileEntry>>` keyed by size; once
+/// more than `n` files are retained, the smallest-key bucket is trimmed (and
+/// dropped once empty). This keeps memory at O(n) regardless of how many
+/// entries are scanned, unlike [`get_largest_files`], which sorts
+/// everything. Directories are ignored. Ties on size land in the same
+/// bucket, so the result may include more than one file of the same size.
+///
+/// # Examples
+///
+/// ```rust
+/// use rfstat::stats::top_n_largest_files;
+/// use rfstat::FileEntry;
+///
+/// let entries: Vec<FileEntry> = Vec::new();
+/// assert!(top_n_largest_files(&entries, 10).is_empty());
+/// ```
+pub fn top_n_largest_files(entries: &[FileEntry], n: usize) -> Vec<FileEntry> {
+    if n == 0 {
+        return Vec::new();
+    }
+
+    let mut by_size: BTreeMap<u64, Vec<FileEntry>> = BTreeMap::new();
+    let mut retained = 0usize;
+
+    for entry in entries {
+        if entry.is_dir {
+            continue;
+        }
+
+        by_size.entry(entry.size).or_default().push(entry.clone());
+        retained += 1;
+
+        while retained > n {
+            let smallest_key = *by_size.keys().next().expect("retained > 0");
+            let bucket = by_size.get_mut(&smallest_key).expect("key just read");
+            bucket.pop();
+            retained -= 1;
+            if bucket.is_empty() {
+                by_size.remove(&smallest_key);
+            }
+        }
+    }
+
+    by_size
+        .into_iter()
+        .rev()
+        .flat_map(|(_, files)| files)
+        .collect()
+}
+
 /// Calculates the top N most common file types.
 ///
 /// # Arguments
@@ -124,40 +236,65 @@ pub fn get_top_file_types(stats: &FileStats, n: usize) -> Vec<(&String, &TypeSta
 /// Calculates directory-specific statistics.
 ///
 /// This function analyzes entries to provide statistics about directory
-/// structure, including depth distribution and directory sizes.
+/// structure, including depth distribution and recursive directory sizes.
 pub fn calculate_directory_stats(entries: &[FileEntry]) -> DirectoryStats {
     let mut dir_stats = DirectoryStats::new();
-    let mut directory_sizes: HashMap<String, u64> = HashMap::new();
+    // Direct (non-recursive) file bytes per directory, seeded with every
+    // known directory so leaf directories with no files still show up.
+    let mut direct_sizes: HashMap<PathBuf, u64> = HashMap::new();
 
     for entry in entries {
         if entry.is_dir {
             dir_stats.total_directories += 1;
 
-            // Calculate directory depth
             let depth = entry.path.components().count();
             dir_stats.max_depth = dir_stats.max_depth.max(depth);
 
-            // Initialize directory size tracking
-            directory_sizes.insert(entry.path.to_string_lossy().to_string(), 0);
-        } else {
-            // Add file size to its parent directory
-            if let Some(parent) = entry.path.parent() {
-                let parent_str = parent.to_string_lossy().to_string();
-                *directory_sizes.entry(parent_str).or_insert(0) += entry.size;
-            }
+            direct_sizes.entry(entry.path.clone()).or_insert(0);
+        } else if let Some(parent) = entry.path.parent() {
+            *direct_sizes.entry(parent.to_path_buf()).or_insert(0) += entry.size;
         }
     }
 
-    // Find largest directory by content size
-    if let Some((largest_dir, largest_size)) = directory_sizes.iter().max_by_key(|(_, &size)| size)
+    let recursive_sizes = roll_up_directory_sizes(direct_sizes);
+
+    if let Some((largest_dir, largest_size)) =
+        recursive_sizes.iter().max_by_key(|(_, &size)| size)
     {
-        dir_stats.largest_directory = Some(largest_dir.clone());
+        dir_stats.largest_directory = Some(largest_dir.to_string_lossy().to_string());
         dir_stats.largest_directory_size = *largest_size;
     }
 
+    let mut directory_sizes: Vec<(PathBuf, u64)> = recursive_sizes.into_iter().collect();
+    directory_sizes.sort_by(|a, b| a.0.cmp(&b.0));
+    dir_stats.directory_sizes = directory_sizes;
+
     dir_stats
 }
 
+/// Folds each directory's direct file bytes up into every ancestor,
+/// turning a map of direct sizes into one of recursive (subtree) sizes.
+///
+/// Directories are processed in descending order of path-component count,
+/// so every child is finalized (its own rollup complete) before its parent
+/// accumulates it, and the running total for a path is memoized in `cache`
+/// the first time it's read so shared prefixes are only summed once.
+fn roll_up_directory_sizes(direct_sizes: HashMap<PathBuf, u64>) -> HashMap<PathBuf, u64> {
+    let mut paths: Vec<PathBuf> = direct_sizes.keys().cloned().collect();
+    paths.sort_by(|a, b| b.components().count().cmp(&a.components().count()));
+
+    let mut cache: HashMap<PathBuf, u64> = direct_sizes;
+    for path in paths {
+        let size = *cache.get(&path).unwrap_or(&0);
+        if let Some(parent) = path.parent() {
+            if cache.contains_key(parent) {
+                *cache.entry(parent.to_path_buf()).or_insert(0) += size;
+            }
+        }
+    }
+    cache
+}
+
 /// Statistics specific to directory structure and organization.
 #[derive(Debug, Clone)]
 pub struct DirectoryStats {
@@ -169,6 +306,8 @@ pub struct DirectoryStats {
     pub largest_directory: Option<String>,
     /// Size of the largest directory's contents
     pub largest_directory_size: u64,
+    /// Every directory's recursive (subtree) size in bytes, sorted by path
+    pub directory_sizes: Vec<(PathBuf, u64)>,
 }
 
 impl DirectoryStats {
@@ -179,6 +318,7 @@ impl DirectoryStats {
             max_depth: 0,
             largest_directory: None,
             largest_directory_size: 0,
+            directory_sizes: Vec::new(),
         }
     }
 
@@ -211,27 +351,496 @@ pub fn calculate_size_percentiles(entries: &[FileEntry], percentiles: &[f64]) ->
         .map(|e| e.size)
         .collect();
 
-    if file_sizes.is_empty() {
+    file_sizes.sort_unstable();
+    percentiles_from_sorted(&file_sizes, percentiles)
+}
+
+/// Shared percentile lookup (the element at index `floor((len - 1) * p)`)
+/// over an already-sorted slice of sizes, used by both [`calculate_size_percentiles`] (the full, exact set)
+/// and [`StatsAccumulator::estimated_percentiles`] (a bounded sample).
+fn percentiles_from_sorted(sorted_sizes: &[u64], percentiles: &[f64]) -> Vec<u64> {
+    if sorted_sizes.is_empty() {
         return vec![0; percentiles.len()];
     }
 
-    file_sizes.sort_unstable();
-
     percentiles
         .iter()
         .map(|&p| {
-            let index = ((file_sizes.len() as f64 - 1.0) * p) as usize;
-            file_sizes[index.min(file_sizes.len() - 1)]
+            let index = ((sorted_sizes.len() as f64 - 1.0) * p) as usize;
+            sorted_sizes[index.min(sorted_sizes.len() - 1)]
         })
         .collect()
 }
 
-/// Generates a summary report of the most important statistics.
+/// Default cap on how many of the largest files [`StatsAccumulator`] keeps.
+const DEFAULT_TOP_N_CAPACITY: usize = 100;
+/// Default cap on the reservoir sample [`StatsAccumulator`] uses to
+/// approximate percentiles.
+const DEFAULT_SAMPLE_CAPACITY: usize = 10_000;
+
+/// A small, fast, non-cryptographic PRNG (xorshift64*), used only to pick
+/// reservoir-sampling slots so the crate doesn't need an external `rand`
+/// dependency just for this. Not suitable for anything security-sensitive.
+struct Xorshift64 {
+    state: u64,
+}
+
+impl Xorshift64 {
+    fn new(seed: u64) -> Self {
+        // xorshift is undefined for a zero state, so force a nonzero seed.
+        Self { state: seed | 1 }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let mut x = self.state;
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        self.state = x;
+        x.wrapping_mul(0x2545_F491_4F6C_DD1D)
+    }
+
+    /// Returns a value uniformly distributed in `0..bound`.
+    fn below(&mut self, bound: u64) -> u64 {
+        self.next_u64() % bound
+    }
+}
+
+/// Incrementally folds [`FileEntry`] values into a [`FileStats`] one at a
+/// time, so a walker can feed entries through `push` without the caller
+/// ever materializing the full tree in memory the way
+/// [`calculate_stats_with_options`] requires.
+///
+/// Percentiles are approximated from a fixed-capacity reservoir sample of
+/// observed sizes (see [`Self::estimated_percentiles`]) rather than the
+/// full sorted set, and the largest files are kept as a bounded top-N
+/// (the same bucketed approach as [`top_n_largest_files`]) instead of every
+/// entry, so both stay O(capacity) regardless of how many entries are
+/// pushed. `finalize` consumes the accumulator and produces a `FileStats`
+/// whose `entries` field holds only that bounded top-N, not the full tree.
+pub struct StatsAccumulator {
+    count_links: bool,
+    total_files: u64,
+    total_dirs: u64,
+    total_size: u64,
+    apparent_size: u64,
+    total_disk_usage: u64,
+    max_file_size: u64,
+    min_file_size: u64,
+    file_types: HashMap<String, TypeStats>,
+    size_distribution: SizeDistribution,
+    disk_usage_distribution: SizeDistribution,
+    seen_inodes: HashSet<(u64, u64)>,
+    sample: Vec<u64>,
+    sample_capacity: usize,
+    sizes_observed: u64,
+    rng: Xorshift64,
+    top_files: BTreeMap<u64, Vec<FileEntry>>,
+    top_n_capacity: usize,
+    top_n_retained: usize,
+}
+
+impl StatsAccumulator {
+    /// Creates an accumulator with the default bounds and the same
+    /// hardlink-deduping behavior as [`calculate_stats`].
+    pub fn new() -> Self {
+        Self::with_options(false, DEFAULT_TOP_N_CAPACITY, DEFAULT_SAMPLE_CAPACITY)
+    }
+
+    /// Creates an accumulator with the default bounds and explicit hardlink
+    /// handling, matching the `count_links` flag on
+    /// [`calculate_stats_with_options`]. Use [`Self::with_options`] to also
+    /// override the top-N/sample bounds.
+    pub fn with_count_links(count_links: bool) -> Self {
+        Self::with_options(count_links, DEFAULT_TOP_N_CAPACITY, DEFAULT_SAMPLE_CAPACITY)
+    }
+
+    /// Creates an accumulator with explicit hardlink handling and bounds.
+    ///
+    /// `count_links` matches the flag on [`calculate_stats_with_options`].
+    /// `top_n_capacity` bounds how many of the largest files are retained;
+    /// `sample_capacity` bounds the reservoir used to approximate
+    /// percentiles. Passing `0` for either disables that feature entirely.
+    pub fn with_options(count_links: bool, top_n_capacity: usize, sample_capacity: usize) -> Self {
+        Self {
+            count_links,
+            total_files: 0,
+            total_dirs: 0,
+            total_size: 0,
+            apparent_size: 0,
+            total_disk_usage: 0,
+            max_file_size: 0,
+            min_file_size: u64::MAX,
+            file_types: HashMap::new(),
+            size_distribution: SizeDistribution::default(),
+            disk_usage_distribution: SizeDistribution::default(),
+            seen_inodes: HashSet::new(),
+            sample: Vec::new(),
+            sample_capacity,
+            sizes_observed: 0,
+            rng: Xorshift64::new(0x5EED),
+            top_files: BTreeMap::new(),
+            top_n_capacity,
+            top_n_retained: 0,
+        }
+    }
+
+    /// Folds one entry's contribution into the running totals.
+    pub fn push(&mut self, entry: &FileEntry) {
+        if entry.is_dir {
+            self.total_dirs += 1;
+            return;
+        }
+
+        self.total_files += 1;
+        self.apparent_size += entry.size;
+
+        // Only the first path seen for a given inode contributes to the
+        // size totals; later hardlinks to the same data are skipped.
+        let already_seen_inode = !self.seen_inodes.insert(entry.inode);
+        let is_duplicate_link = !self.count_links && already_seen_inode;
+
+        let file_type = entry.file_type.as_deref().unwrap_or("no_extension");
+        let type_stats = self.file_types.entry(file_type.to_string()).or_default();
+        type_stats.count += 1;
+
+        if !is_duplicate_link {
+            self.total_size += entry.size;
+            self.total_disk_usage += entry.disk_usage;
+            self.max_file_size = self.max_file_size.max(entry.size);
+            self.min_file_size = self.min_file_size.min(entry.size);
+
+            self.size_distribution.add_size(entry.size);
+            self.disk_usage_distribution.add_size(entry.disk_usage);
+            type_stats.total_size += entry.size;
+
+            self.reservoir_sample(entry.size);
+            self.retain_if_largest(entry);
+        }
+    }
+
+    /// Algorithm R reservoir sampling: every observed size has an equal
+    /// chance of being in the final sample, without ever storing more than
+    /// `sample_capacity` of them.
+    fn reservoir_sample(&mut self, size: u64) {
+        self.sizes_observed += 1;
+        if self.sample.len() < self.sample_capacity {
+            self.sample.push(size);
+        } else {
+            let slot = self.rng.below(self.sizes_observed);
+            if (slot as usize) < self.sample_capacity {
+                self.sample[slot as usize] = size;
+            }
+        }
+    }
+
+    /// Same bucket-and-trim approach as [`top_n_largest_files`], applied
+    /// one entry at a time instead of over a pre-collected slice.
+    fn retain_if_largest(&mut self, entry: &FileEntry) {
+        if self.top_n_capacity == 0 {
+            return;
+        }
+
+        self.top_files
+            .entry(entry.size)
+            .or_default()
+            .push(entry.clone());
+        self.top_n_retained += 1;
+
+        while self.top_n_retained > self.top_n_capacity {
+            let smallest_key = *self.top_files.keys().next().expect("retained > 0");
+            let bucket = self.top_files.get_mut(&smallest_key).expect("key just read");
+            bucket.pop();
+            self.top_n_retained -= 1;
+            if bucket.is_empty() {
+                self.top_files.remove(&smallest_key);
+            }
+        }
+    }
+
+    /// Approximates percentiles from the bounded reservoir sample rather
+    /// than a full sorted size list, which this accumulator never retains.
+    pub fn estimated_percentiles(&self, percentiles: &[f64]) -> Vec<u64> {
+        let mut sample = self.sample.clone();
+        sample.sort_unstable();
+        percentiles_from_sorted(&sample, percentiles)
+    }
+
+    /// Consumes the accumulator, producing a [`FileStats`]. Its `entries`
+    /// field holds only the bounded top-N largest files collected along the
+    /// way, sorted largest first — not the full tree (see the struct docs).
+    pub fn finalize(mut self) -> FileStats {
+        let mut stats = FileStats::new();
+
+        stats.total_files = self.total_files;
+        stats.total_dirs = self.total_dirs;
+        stats.total_size = self.total_size;
+        stats.apparent_size = self.apparent_size;
+        stats.total_disk_usage = self.total_disk_usage;
+
+        if self.total_files > 0 {
+            stats.avg_file_size = self.total_size / self.total_files;
+            stats.avg_disk_usage = self.total_disk_usage / self.total_files;
+        }
+        stats.max_file_size = self.max_file_size;
+        stats.min_file_size = if self.min_file_size == u64::MAX {
+            0
+        } else {
+            self.min_file_size
+        };
+
+        for type_stats in self.file_types.values_mut() {
+            if type_stats.count > 0 {
+                type_stats.avg_size = type_stats.total_size / type_stats.count;
+            }
+        }
+        stats.file_types = self.file_types;
+        stats.size_distribution = self.size_distribution;
+        stats.disk_usage_distribution = self.disk_usage_distribution;
+
+        stats.entries = self.top_files.into_values().flatten().collect();
+        stats.entries.sort_by(|a, b| b.size.cmp(&a.size));
+
+        stats
+    }
+}
+
+impl Default for StatsAccumulator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A single bucket of a [`build_log_size_histogram`] result, covering the
+/// half-open byte range `[lower, upper)`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HistogramBucket {
+    /// Inclusive lower bound in bytes
+    pub lower: u64,
+    /// Exclusive upper bound in bytes
+    pub upper: u64,
+    /// Number of files falling in this range
+    pub count: u64,
+    /// Sum of sizes of files falling in this range
+    pub total_bytes: u64,
+}
+
+impl HistogramBucket {
+    /// Returns the bucket's byte range in human-readable form, e.g.
+    /// "4.10 kB - 8.19 kB" for the `[4096, 8192)` bucket.
+    pub fn range_human(&self) -> String {
+        format!(
+            "{} - {}",
+            humansize::format_size(self.lower, humansize::DECIMAL),
+            humansize::format_size(self.upper, humansize::DECIMAL)
+        )
+    }
+}
+
+/// A bucket is refined further at most this many times, bounding recursion
+/// on pathological inputs (e.g. every file exactly the same size).
+const MAX_HISTOGRAM_REFINE_DEPTH: u32 = 8;
+
+/// Builds a logarithmic size histogram, recursively splitting any bucket
+/// that holds more than 25% of the total file count.
+///
+/// Files are first binned by bit length (`floor(log2(size)) + 1`, or 0 for
+/// an empty file), so bucket `n >= 1`
+/// covers `[2^(n-1), 2^n)` bytes and zero-length files land in their own
+/// `[0, 1)` bucket. Any bucket exceeding the 25% threshold has its byte
+/// range halved and its members re-binned, repeated until every bucket is
+/// under the threshold, it can no longer be split (a one-byte-wide range),
+/// or [`MAX_HISTOGRAM_REFINE_DEPTH`] is reached. Empty buckets are dropped
+/// and the result is sorted ascending by `lower`.
+///
+/// # Examples
+///
+/// ```rust
+/// use rfstat::stats::build_log_size_histogram;
+///
+/// let buckets = build_log_size_histogram(&[1, 2, 3, 1000]);
+/// assert!(!buckets.is_empty());
+/// assert_eq!(buckets.iter().map(|b| b.count).sum::<u64>(), 4);
+/// ```
+pub fn build_log_size_histogram(file_sizes: &[u64]) -> Vec<HistogramBucket> {
+    let total = file_sizes.len() as u64;
+    if total == 0 {
+        return Vec::new();
+    }
+
+    let mut by_bucket: BTreeMap<u64, Vec<u64>> = BTreeMap::new();
+    for &size in file_sizes {
+        let bucket = if size == 0 {
+            0
+        } else {
+            64 - size.leading_zeros() as u64
+        };
+        by_bucket.entry(bucket).or_default().push(size);
+    }
+
+    let mut buckets = Vec::new();
+    for (bucket, sizes) in by_bucket {
+        let (lower, upper) = log_bucket_range(bucket);
+        refine_histogram_bucket(lower, upper, sizes, total, 0, &mut buckets);
+    }
+
+    buckets.sort_by_key(|b| b.lower);
+    buckets
+}
+
+/// Returns the `[lower, upper)` byte range for power-of-two bucket index `n`.
+fn log_bucket_range(n: u64) -> (u64, u64) {
+    if n == 0 {
+        (0, 1)
+    } else {
+        (1u64 << (n - 1), 1u64 << n)
+    }
+}
+
+/// Emits a leaf bucket for `sizes`, or halves `[lower, upper)` and recurses
+/// into both halves when this bucket holds more than 25% of `total` files.
+fn refine_histogram_bucket(
+    lower: u64,
+    upper: u64,
+    sizes: Vec<u64>,
+    total: u64,
+    depth: u32,
+    out: &mut Vec<HistogramBucket>,
+) {
+    let count = sizes.len() as u64;
+    if count == 0 {
+        return;
+    }
+
+    let exceeds_threshold = count.saturating_mul(4) > total;
+    let can_split = upper - lower > 1 && depth < MAX_HISTOGRAM_REFINE_DEPTH;
+
+    if exceeds_threshold && can_split {
+        let mid = lower + (upper - lower) / 2;
+        let (lower_half, upper_half): (Vec<u64>, Vec<u64>) =
+            sizes.into_iter().partition(|&s| s < mid);
+        refine_histogram_bucket(lower, mid, lower_half, total, depth + 1, out);
+        refine_histogram_bucket(mid, upper, upper_half, total, depth + 1, out);
+    } else {
+        let total_bytes = sizes.iter().sum();
+        out.push(HistogramBucket {
+            lower,
+            upper,
+            count,
+            total_bytes,
+        });
+    }
+}
+
+/// Which end of the size spectrum a [`SizeRanking`] reports.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RankMode {
+    /// The N largest files
+    Largest,
+    /// The N smallest files
+    Smallest,
+}
+
+/// Configures the size-based file ranking carried by a [`SummaryReport`].
+///
+/// `min_size` excludes anything smaller than it before ranking, so e.g. a
+/// `Smallest` ranking can ignore zero-byte files and surface the tiniest
+/// *meaningful* files instead.
+#[derive(Debug, Clone, Copy)]
+pub struct SizeRanking {
+    pub mode: RankMode,
+    pub n: usize,
+    pub min_size: u64,
+}
+
+impl SizeRanking {
+    /// The N largest files, with no minimum size.
+    pub fn largest(n: usize) -> Self {
+        Self {
+            mode: RankMode::Largest,
+            n,
+            min_size: 0,
+        }
+    }
+
+    /// The N smallest files, excluding anything under `min_size` bytes.
+    pub fn smallest(n: usize, min_size: u64) -> Self {
+        Self {
+            mode: RankMode::Smallest,
+            n,
+            min_size,
+        }
+    }
+}
+
+impl Default for SizeRanking {
+    fn default() -> Self {
+        Self::largest(5)
+    }
+}
+
+/// Finds the `n` smallest files, optionally excluding anything under
+/// `min_size` bytes (e.g. pass `1` to ignore zero-byte files).
+///
+/// # Arguments
+///
+/// * `entries` - File entries to analyze
+/// * `n` - Number of smallest files to return
+/// * `min_size` - Minimum size in bytes a file must have to be considered
+///
+/// # Returns
+///
+/// A vector of the `n` smallest qualifying files, sorted smallest first
+pub fn get_smallest_files(entries: &[FileEntry], n: usize, min_size: u64) -> Vec<&FileEntry> {
+    let mut files: Vec<&FileEntry> = entries
+        .iter()
+        .filter(|e| !e.is_dir && e.size >= min_size)
+        .collect();
+    files.sort_by(|a, b| a.size.cmp(&b.size));
+    files.into_iter().take(n).collect()
+}
+
+/// Ranks entries according to `ranking`, sharing the same sort-then-take
+/// approach as [`get_largest_files`]/[`get_smallest_files`] but with the
+/// `min_size` floor applied before either end is taken.
+fn rank_files<'a>(entries: &'a [FileEntry], ranking: &SizeRanking) -> Vec<&'a FileEntry> {
+    let mut files: Vec<&FileEntry> = entries
+        .iter()
+        .filter(|e| !e.is_dir && e.size >= ranking.min_size)
+        .collect();
+
+    match ranking.mode {
+        RankMode::Largest => files.sort_by(|a, b| b.size.cmp(&a.size)),
+        RankMode::Smallest => files.sort_by(|a, b| a.size.cmp(&b.size)),
+    }
+
+    files.into_iter().take(ranking.n).collect()
+}
+
+/// Generates a summary report of the most important statistics, ranking
+/// the 5 largest files with no minimum size. Use
+/// [`generate_summary_report_with_ranking`] to report the smallest files,
+/// a different count, or a minimum size floor instead.
 pub fn generate_summary_report(stats: &FileStats) -> SummaryReport {
+    generate_summary_report_with_ranking(stats, SizeRanking::default())
+}
+
+/// Generates a summary report of the most important statistics, using
+/// `ranking` to decide which files populate `SummaryReport::ranked_files`.
+pub fn generate_summary_report_with_ranking(
+    stats: &FileStats,
+    ranking: SizeRanking,
+) -> SummaryReport {
     let dir_stats = calculate_directory_stats(&stats.entries);
-    let largest_files = get_largest_files(&stats.entries, 5);
+    let ranked_files = rank_files(&stats.entries, &ranking);
     let top_types = get_top_file_types(stats, 5);
     let percentiles = calculate_size_percentiles(&stats.entries, &[0.5, 0.75, 0.9, 0.95, 0.99]);
+    let file_sizes: Vec<u64> = stats
+        .entries
+        .iter()
+        .filter(|e| !e.is_dir)
+        .map(|e| e.size)
+        .collect();
 
     SummaryReport {
         total_files: stats.total_files,
@@ -247,11 +856,16 @@ pub fn generate_summary_report(stats: &FileStats) -> SummaryReport {
         },
         most_common_type: top_types.first().map(|(name, _)| (*name).clone()),
         size_distribution: stats.size_distribution.clone(),
-        largest_files: largest_files.into_iter().cloned().collect(),
+        ranking,
+        ranked_files: ranked_files.into_iter().cloned().collect(),
         top_file_types: top_types
             .into_iter()
             .map(|(name, stats)| (name.clone(), stats.clone()))
             .collect(),
+        size_histogram: build_log_size_histogram(&file_sizes),
+        max_directory_depth: dir_stats.max_depth,
+        largest_directory: dir_stats.largest_directory,
+        largest_directory_size: dir_stats.largest_directory_size,
     }
 }
 
@@ -267,8 +881,20 @@ pub struct SummaryReport {
     pub smallest_file_size: u64,
     pub most_common_type: Option<String>,
     pub size_distribution: SizeDistribution,
-    pub largest_files: Vec<FileEntry>,
+    /// The ranking that selected `ranked_files`
+    pub ranking: SizeRanking,
+    /// The files selected by `ranking` (largest or smallest, per its mode)
+    pub ranked_files: Vec<FileEntry>,
     pub top_file_types: Vec<(String, TypeStats)>,
+    /// Logarithmic size histogram; see [`build_log_size_histogram`]
+    pub size_histogram: Vec<HistogramBucket>,
+    /// Deepest directory nesting found, from [`calculate_directory_stats`]
+    pub max_directory_depth: usize,
+    /// Path of the directory with the largest recursive (subtree) size,
+    /// from [`calculate_directory_stats`]
+    pub largest_directory: Option<String>,
+    /// That directory's recursive size in bytes
+    pub largest_directory_size: u64,
 }
 
 impl SummaryReport {
@@ -300,14 +926,132 @@ mod tests {
         is_dir: bool,
         file_type: Option<&str>,
     ) -> FileEntry {
+        use std::collections::hash_map::DefaultHasher;
+        use std::hash::{Hash, Hasher};
+
+        // Give each distinct name a distinct synthetic inode so hardlink
+        // dedup logic doesn't treat unrelated test files as the same data.
+        let mut hasher = DefaultHasher::new();
+        name.hash(&mut hasher);
+        let inode = (0, hasher.finish());
+
         FileEntry {
             path: PathBuf::from(name),
             size,
+            disk_usage: size,
+            inode,
             is_dir,
             modified: Utc::now(),
             permissions: 0o644,
             file_type: file_type.map(|s| s.to_string()),
+            is_symlink: false,
+            symlink_target: None,
+        }
+    }
+
+    #[test]
+    fn test_calculate_stats_parallel_below_threshold_matches_serial() {
+        let entries = vec![
+            create_test_entry("file1.txt", 1000, false, Some("txt")),
+            create_test_entry("file2.txt", 2000, false, Some("jpg")),
+            create_test_entry("dir1", 0, true, None),
+        ];
+
+        let serial = calculate_stats_with_options(&entries, true);
+        let parallel = calculate_stats_parallel(&entries);
+
+        assert_eq!(parallel.total_files, serial.total_files);
+        assert_eq!(parallel.total_dirs, serial.total_dirs);
+        assert_eq!(parallel.total_size, serial.total_size);
+        assert_eq!(parallel.max_file_size, serial.max_file_size);
+        assert_eq!(parallel.min_file_size, serial.min_file_size);
+    }
+
+    #[test]
+    fn test_calculate_stats_parallel_above_threshold_matches_serial() {
+        let entries: Vec<FileEntry> = (0..(PARALLEL_STATS_THRESHOLD + 500))
+            .map(|i| {
+                let ext = if i % 2 == 0 { "txt" } else { "bin" };
+                create_test_entry(&format!("file{i}.dat"), (i as u64) + 1, false, Some(ext))
+            })
+            .collect();
+
+        let serial = calculate_stats_with_options(&entries, true);
+        let parallel = calculate_stats_parallel(&entries);
+
+        assert_eq!(parallel.total_files, serial.total_files);
+        assert_eq!(parallel.total_size, serial.total_size);
+        assert_eq!(parallel.max_file_size, serial.max_file_size);
+        assert_eq!(parallel.min_file_size, serial.min_file_size);
+        assert_eq!(parallel.avg_file_size, serial.avg_file_size);
+        assert_eq!(
+            parallel.size_distribution.tiny, serial.size_distribution.tiny
+        );
+        assert_eq!(
+            parallel.file_types.get("txt").unwrap().count,
+            serial.file_types.get("txt").unwrap().count
+        );
+        assert_eq!(
+            parallel.file_types.get("txt").unwrap().total_size,
+            serial.file_types.get("txt").unwrap().total_size
+        );
+    }
+
+    #[test]
+    fn test_stats_accumulator_matches_calculate_stats() {
+        let entries = vec![
+            create_test_entry("file1.txt", 1000, false, Some("txt")),
+            create_test_entry("file2.txt", 2000, false, Some("txt")),
+            create_test_entry("dir1", 0, true, None),
+        ];
+
+        let expected = calculate_stats(&entries);
+
+        let mut acc = StatsAccumulator::new();
+        for entry in &entries {
+            acc.push(entry);
+        }
+        let actual = acc.finalize();
+
+        assert_eq!(actual.total_files, expected.total_files);
+        assert_eq!(actual.total_dirs, expected.total_dirs);
+        assert_eq!(actual.total_size, expected.total_size);
+        assert_eq!(actual.avg_file_size, expected.avg_file_size);
+        assert_eq!(actual.max_file_size, expected.max_file_size);
+        assert_eq!(actual.min_file_size, expected.min_file_size);
+        assert_eq!(
+            actual.file_types.get("txt").unwrap().count,
+            expected.file_types.get("txt").unwrap().count
+        );
+    }
+
+    #[test]
+    fn test_stats_accumulator_bounds_top_n_and_sample() {
+        let mut acc = StatsAccumulator::with_options(false, 2, 3);
+        for i in 0..10u64 {
+            acc.push(&create_test_entry(&format!("f{i}.txt"), i + 1, false, Some("txt")));
         }
+        // The reservoir never grows past its capacity of 3.
+        assert_eq!(acc.sample.len(), 3);
+        let stats = acc.finalize();
+
+        // Only the top-2 capacity's worth of entries is retained.
+        assert_eq!(stats.entries.len(), 2);
+        assert_eq!(stats.entries[0].size, 10);
+        assert_eq!(stats.entries[1].size, 9);
+        // Every push was counted even though only a bounded sample/top-N
+        // of the underlying data was kept.
+        assert_eq!(stats.total_files, 10);
+    }
+
+    #[test]
+    fn test_stats_accumulator_estimated_percentiles_within_observed_range() {
+        let mut acc = StatsAccumulator::new();
+        for size in [10, 20, 30, 40, 50] {
+            acc.push(&create_test_entry(&format!("{size}.bin"), size, false, None));
+        }
+        let estimated = acc.estimated_percentiles(&[0.5]);
+        assert!(estimated[0] >= 10 && estimated[0] <= 50);
     }
 
     #[test]
@@ -344,6 +1088,29 @@ mod tests {
         assert_eq!(txt_stats.avg_size, 1500);
     }
 
+    #[test]
+    fn test_calculate_stats_dedups_hardlinks() {
+        let shared_inode = (7, 42);
+        let mut linked_copy = create_test_entry("link.txt", 1000, false, Some("txt"));
+        linked_copy.inode = shared_inode;
+        let mut original = create_test_entry("original.txt", 1000, false, Some("txt"));
+        original.inode = shared_inode;
+
+        let entries = vec![original, linked_copy];
+
+        let deduped = calculate_stats_with_options(&entries, false);
+        assert_eq!(deduped.total_files, 2);
+        assert_eq!(deduped.total_size, 1000);
+        // apparent_size always counts every path, regardless of count_links,
+        // so callers can compare it against the deduped total_size.
+        assert_eq!(deduped.apparent_size, 2000);
+
+        let naive = calculate_stats_with_options(&entries, true);
+        assert_eq!(naive.total_files, 2);
+        assert_eq!(naive.total_size, 2000);
+        assert_eq!(naive.apparent_size, 2000);
+    }
+
     #[test]
     fn test_get_largest_files() {
         let entries = vec![
@@ -360,6 +1127,72 @@ mod tests {
         assert_eq!(largest[1].size, 500);
     }
 
+    #[test]
+    fn test_get_smallest_files() {
+        let entries = vec![
+            create_test_entry("small.txt", 100, false, Some("txt")),
+            create_test_entry("large.txt", 1000, false, Some("txt")),
+            create_test_entry("empty.txt", 0, false, Some("txt")),
+            create_test_entry("dir", 0, true, None),
+        ];
+
+        let smallest = get_smallest_files(&entries, 2, 0);
+        assert_eq!(smallest.len(), 2);
+        assert_eq!(smallest[0].size, 0);
+        assert_eq!(smallest[1].size, 100);
+    }
+
+    #[test]
+    fn test_get_smallest_files_excludes_below_min_size() {
+        let entries = vec![
+            create_test_entry("small.txt", 100, false, Some("txt")),
+            create_test_entry("empty.txt", 0, false, Some("txt")),
+        ];
+
+        let smallest = get_smallest_files(&entries, 5, 1);
+        assert_eq!(smallest.len(), 1);
+        assert_eq!(smallest[0].size, 100);
+    }
+
+    #[test]
+    fn test_generate_summary_report_with_ranking_smallest() {
+        let entries = vec![
+            create_test_entry("a.txt", 100, false, Some("txt")),
+            create_test_entry("b.txt", 1000, false, Some("txt")),
+            create_test_entry("c.txt", 0, false, Some("txt")),
+        ];
+        let stats = calculate_stats(&entries);
+
+        let report =
+            generate_summary_report_with_ranking(&stats, SizeRanking::smallest(5, 1));
+
+        assert_eq!(report.ranked_files.len(), 2);
+        assert_eq!(report.ranked_files[0].size, 100);
+        assert_eq!(report.ranked_files[1].size, 1000);
+    }
+
+    #[test]
+    fn test_top_n_largest_files() {
+        let entries = vec![
+            create_test_entry("small.txt", 100, false, Some("txt")),
+            create_test_entry("large.txt", 1000, false, Some("txt")),
+            create_test_entry("medium.txt", 500, false, Some("txt")),
+            create_test_entry("dir", 0, true, None),
+        ];
+
+        let top = top_n_largest_files(&entries, 2);
+
+        assert_eq!(top.len(), 2);
+        assert_eq!(top[0].size, 1000);
+        assert_eq!(top[1].size, 500);
+    }
+
+    #[test]
+    fn test_top_n_largest_files_zero_returns_empty() {
+        let entries = vec![create_test_entry("a.txt", 10, false, Some("txt"))];
+        assert!(top_n_largest_files(&entries, 0).is_empty());
+    }
+
     #[test]
     fn test_size_distribution() {
         let entries = vec![
@@ -378,4 +1211,86 @@ mod tests {
         assert_eq!(stats.size_distribution.large, 1);
         assert_eq!(stats.size_distribution.huge, 1);
     }
+
+    #[test]
+    fn test_calculate_directory_stats_recursive_rollup() {
+        // dir1/ has 100 bytes direct + dir1/sub/ has 900 bytes, so dir1's
+        // recursive total (1000) should beat dir2's direct-only total (500)
+        // even though dir2 looks larger under the old direct-only scheme.
+        let entries = vec![
+            create_test_entry("dir1", 0, true, None),
+            create_test_entry("dir1/a.txt", 100, false, Some("txt")),
+            create_test_entry("dir1/sub", 0, true, None),
+            create_test_entry("dir1/sub/b.txt", 900, false, Some("txt")),
+            create_test_entry("dir2", 0, true, None),
+            create_test_entry("dir2/c.txt", 500, false, Some("txt")),
+        ];
+
+        let dir_stats = calculate_directory_stats(&entries);
+
+        assert_eq!(dir_stats.largest_directory, Some("dir1".to_string()));
+        assert_eq!(dir_stats.largest_directory_size, 1000);
+
+        let sizes: HashMap<_, _> = dir_stats.directory_sizes.into_iter().collect();
+        assert_eq!(sizes[&PathBuf::from("dir1")], 1000);
+        assert_eq!(sizes[&PathBuf::from("dir1/sub")], 900);
+        assert_eq!(sizes[&PathBuf::from("dir2")], 500);
+    }
+
+    #[test]
+    fn test_build_log_size_histogram_basic_bucketing() {
+        // 1 falls in bucket [1,2) and 1000 in [512,1024). 2 and 3 share
+        // [2,4), which holds 2 of the 4 files (over 25%), so it is split
+        // into [2,3) and [3,4).
+        let buckets = build_log_size_histogram(&[1, 2, 3, 1000]);
+        assert_eq!(buckets.iter().map(|b| b.count).sum::<u64>(), 4);
+        let b1 = buckets.iter().find(|b| b.lower == 1 && b.upper == 2).unwrap();
+        assert_eq!(b1.count, 1);
+        assert_eq!(b1.total_bytes, 1);
+        let b3 = buckets.iter().find(|b| b.lower == 3 && b.upper == 4).unwrap();
+        assert_eq!(b3.count, 1);
+        let b1000 = buckets
+            .iter()
+            .find(|b| b.lower == 512 && b.upper == 1024)
+            .unwrap();
+        assert_eq!(b1000.count, 1);
+    }
+
+    #[test]
+    fn test_build_log_size_histogram_zero_size_bucket() {
+        let buckets = build_log_size_histogram(&[0, 0, 5]);
+        let zero_bucket = buckets.iter().find(|b| b.lower == 0 && b.upper == 1).unwrap();
+        assert_eq!(zero_bucket.count, 2);
+        assert_eq!(zero_bucket.total_bytes, 0);
+    }
+
+    #[test]
+    fn test_build_log_size_histogram_refines_skewed_bucket() {
+        // 9 of 10 files share the [64,128) bucket (size 100), well over the
+        // 25% threshold, so it must be split into narrower ranges.
+        let mut sizes = vec![100u64; 9];
+        sizes.push(1000);
+        let buckets = build_log_size_histogram(&sizes);
+        assert!(
+            buckets.iter().all(|b| b.upper - b.lower <= 1 || b.count * 4 <= 10),
+            "every bucket should be under threshold or unsplittable: {buckets:?}"
+        );
+        assert_eq!(buckets.iter().map(|b| b.count).sum::<u64>(), 10);
+    }
+
+    #[test]
+    fn test_build_log_size_histogram_empty_input() {
+        assert!(build_log_size_histogram(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_generate_summary_report_includes_histogram() {
+        let entries = vec![
+            create_test_entry("a.txt", 10, false, Some("txt")),
+            create_test_entry("b.txt", 20, false, Some("txt")),
+        ];
+        let stats = calculate_stats(&entries);
+        let report = generate_summary_report(&stats);
+        assert_eq!(report.size_histogram.iter().map(|b| b.count).sum::<u64>(), 2);
+    }
 }
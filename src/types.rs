@@ -19,10 +19,14 @@ use std::path::PathBuf;
 /// let entry = FileEntry {
 ///     path: PathBuf::from("/home/user/document.txt"),
 ///     size: 1024,
+///     disk_usage: 4096,
+///     inode: (0, 0),
 ///     is_dir: false,
 ///     modified: chrono::Utc::now(),
 ///     permissions: 0o644,
 ///     file_type: Some("txt".to_string()),
+///     is_symlink: false,
+///     symlink_target: None,
 /// };
 ///
 /// assert_eq!(entry.size_human(), "1.02 kB");
@@ -33,6 +37,13 @@ pub struct FileEntry {
     pub path: PathBuf,
     /// Size in bytes
     pub size: u64,
+    /// Actual space allocated on disk, in bytes (accounts for block
+    /// rounding and sparse files; equal to `size` where unavailable)
+    pub disk_usage: u64,
+    /// `(device_id, inode)` identifying the underlying filesystem object.
+    /// Multiple hardlinked paths share the same identity; used to avoid
+    /// double-counting their size.
+    pub inode: (u64, u64),
     /// Whether this entry is a directory
     pub is_dir: bool,
     /// Last modified timestamp
@@ -41,6 +52,11 @@ pub struct FileEntry {
     pub permissions: u32,
     /// File extension/type (if applicable)
     pub file_type: Option<String>,
+    /// Whether this path is itself a symbolic link (its other fields
+    /// describe the link's resolved target, not the link itself)
+    pub is_symlink: bool,
+    /// The link's target path, if `is_symlink` and it could be read
+    pub symlink_target: Option<PathBuf>,
 }
 
 impl FileEntry {
@@ -56,10 +72,14 @@ impl FileEntry {
     /// let entry = FileEntry {
     ///     path: PathBuf::from("test.txt"),
     ///     size: 2048,
+    ///     disk_usage: 4096,
+    ///     inode: (0, 0),
     ///     is_dir: false,
     ///     modified: Utc::now(),
     ///     permissions: 0o644,
     ///     file_type: Some("txt".to_string()),
+    ///     is_symlink: false,
+    ///     symlink_target: None,
     /// };
     ///
     /// assert_eq!(entry.size_human(), "2.05 kB");
@@ -68,6 +88,11 @@ impl FileEntry {
         humansize::format_size(self.size, humansize::DECIMAL)
     }
 
+    /// Returns the on-disk allocated size in human-readable format.
+    pub fn disk_usage_human(&self) -> String {
+        humansize::format_size(self.disk_usage, humansize::DECIMAL)
+    }
+
     /// Returns the file name without the full path.
     pub fn name(&self) -> String {
         self.path
@@ -88,20 +113,35 @@ pub struct FileStats {
     pub total_files: u64,
     /// Total number of directories
     pub total_dirs: u64,
-    /// Total size of all files in bytes
+    /// Total size of all files in bytes, counting each hardlinked inode
+    /// only once (see [`crate::stats::calculate_stats_with_options`])
     pub total_size: u64,
+    /// Naive sum of every file's size, counting hardlinked copies of the
+    /// same inode once per path. Compare against `total_size` to see how
+    /// much of the apparent usage is actually shared inodes.
+    pub apparent_size: u64,
     /// Average file size in bytes
     pub avg_file_size: u64,
     /// Largest file size in bytes
     pub max_file_size: u64,
     /// Smallest file size in bytes
     pub min_file_size: u64,
+    /// Total space actually allocated on disk across all files, in bytes
+    pub total_disk_usage: u64,
+    /// Average on-disk allocated size per file, in bytes
+    pub avg_disk_usage: u64,
     /// Breakdown by file extension
     pub file_types: HashMap<String, TypeStats>,
-    /// Size distribution buckets
+    /// Size distribution buckets, based on apparent size
     pub size_distribution: SizeDistribution,
+    /// Size distribution buckets, based on on-disk allocated size
+    pub disk_usage_distribution: SizeDistribution,
     /// Individual file entries
     pub entries: Vec<FileEntry>,
+    /// Groups of byte-identical files, populated when duplicate detection runs
+    pub duplicate_groups: Vec<DuplicateGroup>,
+    /// Total bytes that could be reclaimed across all duplicate groups
+    pub wasted_bytes: u64,
 }
 
 impl FileStats {
@@ -111,12 +151,18 @@ impl FileStats {
             total_files: 0,
             total_dirs: 0,
             total_size: 0,
+            apparent_size: 0,
             avg_file_size: 0,
             max_file_size: 0,
             min_file_size: u64::MAX,
+            total_disk_usage: 0,
+            avg_disk_usage: 0,
             file_types: HashMap::new(),
             size_distribution: SizeDistribution::new(),
+            disk_usage_distribution: SizeDistribution::new(),
             entries: Vec::new(),
+            duplicate_groups: Vec::new(),
+            wasted_bytes: 0,
         }
     }
 
@@ -135,10 +181,72 @@ impl FileStats {
         humansize::format_size(self.total_size, humansize::DECIMAL)
     }
 
+    /// Returns the naive (non-deduplicated) apparent size in human-readable
+    /// format.
+    pub fn apparent_size_human(&self) -> String {
+        humansize::format_size(self.apparent_size, humansize::DECIMAL)
+    }
+
     /// Returns the average file size in human-readable format.
     pub fn avg_file_size_human(&self) -> String {
         humansize::format_size(self.avg_file_size, humansize::DECIMAL)
     }
+
+    /// Returns the total on-disk allocated size in human-readable format.
+    pub fn total_disk_usage_human(&self) -> String {
+        humansize::format_size(self.total_disk_usage, humansize::DECIMAL)
+    }
+
+    /// Returns the average on-disk allocated size in human-readable format.
+    pub fn avg_disk_usage_human(&self) -> String {
+        humansize::format_size(self.avg_disk_usage, humansize::DECIMAL)
+    }
+
+    /// Commutatively folds `other` into `self`, summing counts/sizes,
+    /// combining per-type breakdowns and size distributions, and taking
+    /// the wider of the two `min`/`max` file sizes.
+    ///
+    /// Used by [`crate::stats::calculate_stats_parallel`] to merge partial
+    /// statistics computed over independent chunks of entries back into
+    /// one whole. Average fields are recomputed from the merged totals
+    /// rather than averaged, since naively averaging two averages would be
+    /// skewed by chunks of different sizes.
+    pub fn merge(mut self, other: Self) -> Self {
+        self.total_files += other.total_files;
+        self.total_dirs += other.total_dirs;
+        self.total_size += other.total_size;
+        self.apparent_size += other.apparent_size;
+        self.total_disk_usage += other.total_disk_usage;
+        self.max_file_size = self.max_file_size.max(other.max_file_size);
+        self.min_file_size = self.min_file_size.min(other.min_file_size);
+
+        for (file_type, other_stats) in other.file_types {
+            let type_stats = self.file_types.entry(file_type).or_default();
+            type_stats.count += other_stats.count;
+            type_stats.total_size += other_stats.total_size;
+        }
+        for type_stats in self.file_types.values_mut() {
+            if type_stats.count > 0 {
+                type_stats.avg_size = type_stats.total_size / type_stats.count;
+            }
+        }
+
+        self.size_distribution = self.size_distribution.merge(&other.size_distribution);
+        self.disk_usage_distribution = self
+            .disk_usage_distribution
+            .merge(&other.disk_usage_distribution);
+
+        self.entries.extend(other.entries);
+        self.duplicate_groups.extend(other.duplicate_groups);
+        self.wasted_bytes += other.wasted_bytes;
+
+        if self.total_files > 0 {
+            self.avg_file_size = self.total_size / self.total_files;
+            self.avg_disk_usage = self.total_disk_usage / self.total_files;
+        }
+
+        self
+    }
 }
 
 impl Default for FileStats {
@@ -147,6 +255,31 @@ impl Default for FileStats {
     }
 }
 
+/// A group of byte-identical files discovered by duplicate detection.
+///
+/// See [`crate::duplicates::find_duplicates`] for how groups are produced.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DuplicateGroup {
+    /// Size in bytes shared by every file in the group
+    pub size: u64,
+    /// Paths of all files sharing this content
+    pub files: Vec<PathBuf>,
+    /// Bytes that could be reclaimed by removing all but one copy
+    pub wasted_bytes: u64,
+}
+
+impl DuplicateGroup {
+    /// Returns the per-file size in human-readable format.
+    pub fn size_human(&self) -> String {
+        humansize::format_size(self.size, humansize::DECIMAL)
+    }
+
+    /// Returns the reclaimable space in human-readable format.
+    pub fn wasted_bytes_human(&self) -> String {
+        humansize::format_size(self.wasted_bytes, humansize::DECIMAL)
+    }
+}
+
 /// Statistics for a specific file type/extension.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TypeStats {
@@ -217,6 +350,17 @@ impl SizeDistribution {
             _ => self.huge += 1,
         }
     }
+
+    /// Combines bucket counts with `other`, for merging distributions
+    /// computed over independent subsets of the same tree.
+    pub fn merge(mut self, other: &Self) -> Self {
+        self.tiny += other.tiny;
+        self.small += other.small;
+        self.medium += other.medium;
+        self.large += other.large;
+        self.huge += other.huge;
+        self
+    }
 }
 
 impl Default for SizeDistribution {
@@ -232,10 +376,23 @@ pub enum OutputFormat {
     Table,
     /// JSON format for programmatic use
     Json,
+    /// Newline-delimited JSON: one compact object per file entry, followed
+    /// by a `{"type":"summary",...}` object, for streaming into
+    /// `jq`-style pipelines without buffering the whole document
+    JsonLines,
     /// CSV format for spreadsheet import
     Csv,
     /// Compact summary format
     Summary,
+    /// Duplicate-file report grouped by reclaimable space
+    Duplicates,
+    /// Indented tree view with recursive per-directory totals
+    Tree,
+    /// Proportional horizontal bar chart of file types and size distribution
+    Bars,
+    /// Detailed report: recursive directory-size stats, a log-scale size
+    /// histogram, and the ranked (largest or smallest) files
+    Report,
 }
 
 impl Default for OutputFormat {
@@ -244,6 +401,49 @@ impl Default for OutputFormat {
     }
 }
 
+/// Selects how byte counts are rendered as human-readable text.
+///
+/// `size_human`/`total_size_human`/etc. on [`FileEntry`] and [`FileStats`]
+/// always use [`SizeFormat::Decimal`]; the file table, summary header, tree,
+/// report, and CSV/JSON Lines `size_human` fields instead route sizes
+/// through [`format_size_as`] with the [`SizeFormat`] chosen via
+/// `FormatterOptions.size_format`. The file-type table and bars, duplicate
+/// report, and compact summary line always use decimal units.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum SizeFormat {
+    /// 1000-based units: B, kB, MB, GB, ...
+    Decimal,
+    /// 1024-based units: B, KiB, MiB, GiB, ...
+    Binary,
+    /// Report sizes as an integer count of the given block size in bytes,
+    /// the way `df --block-size` does (e.g. `BlockSize(1_048_576)` prints
+    /// "3" for a 2.5 MiB file instead of "2.50 MiB")
+    BlockSize(u64),
+}
+
+impl Default for SizeFormat {
+    fn default() -> Self {
+        SizeFormat::Decimal
+    }
+}
+
+/// Renders `bytes` according to `format`. Every formatter view that honors
+/// `FormatterOptions.size_format` converts byte counts to text through
+/// here, so those views render sizes the same way.
+pub fn format_size_as(bytes: u64, format: SizeFormat) -> String {
+    match format {
+        SizeFormat::Decimal => humansize::format_size(bytes, humansize::DECIMAL),
+        SizeFormat::Binary => humansize::format_size(bytes, humansize::BINARY),
+        SizeFormat::BlockSize(block) => {
+            if block == 0 {
+                return humansize::format_size(bytes, humansize::DECIMAL);
+            }
+            let blocks = (bytes + block - 1) / block;
+            blocks.to_string()
+        }
+    }
+}
+
 /// Sorting options for file listings.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum SortBy {
@@ -276,6 +476,26 @@ pub struct Config {
     pub recursive: bool,
     /// Maximum depth for recursive scanning
     pub max_depth: Option<usize>,
+    /// Whether the table/summary/distribution should report on-disk
+    /// allocated size instead of apparent (logical) size
+    pub use_disk_usage: bool,
+    /// Whether to count every hardlinked path toward totals (the naive
+    /// behavior), instead of counting each inode's size only once
+    pub count_links: bool,
+    /// Number of worker threads used for parallel directory traversal.
+    /// `None` means use all available parallelism.
+    pub threads: Option<usize>,
+    /// Glob patterns for paths to prune during scanning; matching
+    /// directories are never descended into and matching files are
+    /// skipped entirely
+    pub exclude: Vec<String>,
+    /// Whether to look inside `.tar`, `.tar.gz`/`.tgz`, and `.zip` files
+    /// and report their members alongside regular entries
+    pub inspect_archives: bool,
+    /// Whether to descend into symlinked directories during traversal.
+    /// Cycles are guarded against by tracking visited `(device, inode)`
+    /// pairs; a directory reached a second time is skipped with a warning.
+    pub follow_symlinks: bool,
 }
 
 impl Default for Config {
@@ -286,6 +506,44 @@ impl Default for Config {
             show_hidden: false,
             recursive: true,
             max_depth: None,
+            use_disk_usage: false,
+            count_links: false,
+            threads: None,
+            exclude: Vec::new(),
+            inspect_archives: false,
+            follow_symlinks: false,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_size_as_decimal() {
+        assert_eq!(
+            format_size_as(1_500_000, SizeFormat::Decimal),
+            humansize::format_size(1_500_000u64, humansize::DECIMAL)
+        );
+    }
+
+    #[test]
+    fn test_format_size_as_binary() {
+        assert_eq!(
+            format_size_as(1_048_576, SizeFormat::Binary),
+            humansize::format_size(1_048_576u64, humansize::BINARY)
+        );
+    }
+
+    #[test]
+    fn test_format_size_as_block_size_rounds_up() {
+        assert_eq!(format_size_as(2_621_440, SizeFormat::BlockSize(1_048_576)), "3");
+        assert_eq!(format_size_as(1_048_576, SizeFormat::BlockSize(1_048_576)), "1");
+    }
+
+    #[test]
+    fn test_format_size_as_block_size_zero_falls_back_to_decimal() {
+        assert_eq!(format_size_as(1_500_000, SizeFormat::BlockSize(0)), "1.50 MB");
+    }
+}
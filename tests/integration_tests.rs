@@ -9,6 +9,21 @@ use std::fs::{self, File};
 use std::io::Write;
 use tempfile::TempDir;
 
+/// Creates a `.tar` archive containing a single member, `nested/file.txt`.
+fn create_test_tar(path: &std::path::Path) {
+    let file = File::create(path).unwrap();
+    let mut builder = tar::Builder::new(file);
+    let data = b"inner contents";
+    let mut header = tar::Header::new_gnu();
+    header.set_size(data.len() as u64);
+    header.set_mode(0o644);
+    header.set_cksum();
+    builder
+        .append_data(&mut header, "nested/file.txt", &data[..])
+        .unwrap();
+    builder.finish().unwrap();
+}
+
 /// Helper function to create a test directory structure
 fn create_test_directory() -> TempDir {
     let temp_dir = TempDir::new().unwrap();
@@ -116,14 +131,176 @@ fn test_extension_filtering() {
         .stdout(predicate::str::contains(".dat").not());
 }
 
+#[test]
+fn test_exclude_glob_filtering() {
+    let temp_dir = create_test_directory();
+
+    let mut cmd = cargo_bin_cmd!("rfstat");
+    cmd.arg(temp_dir.path())
+        .arg("--exclude")
+        .arg("*/subdir/*")
+        .arg("--format")
+        .arg("csv")
+        .arg("--quiet")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("small.txt"))
+        .stdout(predicate::str::contains("nested.conf").not());
+}
+
+#[test]
+fn test_include_glob_filtering() {
+    let temp_dir = create_test_directory();
+
+    let mut cmd = cargo_bin_cmd!("rfstat");
+    cmd.arg(temp_dir.path())
+        .arg("--include")
+        .arg("*.txt")
+        .arg("--format")
+        .arg("csv")
+        .arg("--quiet")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("small.txt"))
+        .stdout(predicate::str::contains(".log").not());
+}
+
+#[test]
+fn test_inspect_archives_flag() {
+    let temp_dir = TempDir::new().unwrap();
+    create_test_tar(&temp_dir.path().join("backup.tar"));
+
+    // Without the flag, only the archive itself shows up.
+    let mut cmd = cargo_bin_cmd!("rfstat");
+    cmd.arg(temp_dir.path())
+        .arg("--format")
+        .arg("csv")
+        .arg("--quiet")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("backup.tar!/nested/file.txt").not());
+
+    // With the flag, the tar member is reported too.
+    let mut cmd = cargo_bin_cmd!("rfstat");
+    cmd.arg(temp_dir.path())
+        .arg("--inspect-archives")
+        .arg("--format")
+        .arg("csv")
+        .arg("--quiet")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("backup.tar!/nested/file.txt"));
+}
+
+#[test]
+fn test_name_glob_filtering() {
+    let temp_dir = create_test_directory();
+
+    let mut cmd = cargo_bin_cmd!("rfstat");
+    cmd.arg(temp_dir.path())
+        .arg("--name")
+        .arg("small.*")
+        .arg("--format")
+        .arg("csv")
+        .arg("--quiet")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("small.txt"))
+        .stdout(predicate::str::contains("medium.log").not());
+}
+
+#[test]
+fn test_extension_regex_filtering() {
+    let temp_dir = create_test_directory();
+
+    let mut cmd = cargo_bin_cmd!("rfstat");
+    cmd.arg(temp_dir.path())
+        .arg("--extension-regex")
+        .arg("^lo?g$")
+        .arg("--format")
+        .arg("csv")
+        .arg("--quiet")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("medium.log"))
+        .stdout(predicate::str::contains("small.txt").not());
+}
+
+// The duplicate-detection subsystem itself (group-by-size, partial/full
+// hashing, wasted-bytes accounting) landed under chunk0-1; this is just
+// end-to-end CLI coverage for the `--duplicates` flag on top of it.
+#[test]
+fn test_duplicates_flag_reports_reclaimable_space() {
+    let temp_dir = create_test_directory();
+
+    // small.txt and its copy are byte-identical; everything else in
+    // create_test_directory() has a unique size or content.
+    fs::copy(
+        temp_dir.path().join("small.txt"),
+        temp_dir.path().join("small-copy.txt"),
+    )
+    .unwrap();
+
+    let mut cmd = cargo_bin_cmd!("rfstat");
+    cmd.arg(temp_dir.path())
+        .arg("--duplicates")
+        .arg("--quiet")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("small.txt"))
+        .stdout(predicate::str::contains("small-copy.txt"))
+        .stdout(predicate::str::contains("wasted").or(predicate::str::contains("Wasted")));
+}
+
+#[test]
+fn test_top_n_largest_files() {
+    let temp_dir = create_test_directory();
+
+    let mut cmd = cargo_bin_cmd!("rfstat");
+    cmd.arg(temp_dir.path())
+        .arg("--top")
+        .arg("1")
+        .arg("--format")
+        .arg("csv")
+        .arg("--quiet")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("large.dat"))
+        .stdout(predicate::str::contains("small.txt").not());
+}
+
+#[test]
+fn test_follow_symlinks_flag_descends_into_linked_directory() {
+    let target_dir = TempDir::new().unwrap();
+    File::create(target_dir.path().join("inside.txt")).unwrap();
+
+    let scan_root = TempDir::new().unwrap();
+    std::os::unix::fs::symlink(target_dir.path(), scan_root.path().join("link")).unwrap();
+
+    let mut cmd = cargo_bin_cmd!("rfstat");
+    cmd.arg(scan_root.path())
+        .arg("--quiet")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("inside.txt").not());
+
+    let mut cmd = cargo_bin_cmd!("rfstat");
+    cmd.arg(scan_root.path())
+        .arg("--follow-symlinks")
+        .arg("--quiet")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("inside.txt"));
+}
+
 #[test]
 fn test_size_filtering() {
     let temp_dir = create_test_directory();
 
     let mut cmd = cargo_bin_cmd!("rfstat");
     cmd.arg(temp_dir.path())
-        .arg("--min-size")
-        .arg("1KB")
+        .arg("--size")
+        .arg("+1KB")
         .arg("--format")
         .arg("csv")
         .arg("--quiet")
@@ -264,7 +441,7 @@ fn test_invalid_size_format() {
 
     let mut cmd = cargo_bin_cmd!("rfstat");
     cmd.arg(temp_dir.path())
-        .arg("--min-size")
+        .arg("--size")
         .arg("invalid_size")
         .assert()
         .failure()